@@ -0,0 +1,34 @@
+// This file was generated by gir (32b0f11) from gir-files (71d73f0)
+// DO NOT EDIT
+
+use ffi;
+use glib::translate::*;
+
+glib_wrapper! {
+    pub struct AttrList(Shared<ffi::PangoAttrList>);
+
+    match fn {
+        ref => |ptr| ffi::pango_attr_list_ref(ptr),
+        unref => |ptr| ffi::pango_attr_list_unref(ptr),
+    }
+}
+
+impl AttrList {
+    pub fn new() -> AttrList {
+        unsafe {
+            from_glib_full(ffi::pango_attr_list_new())
+        }
+    }
+
+    pub fn copy(&self) -> Option<AttrList> {
+        unsafe {
+            from_glib_full(ffi::pango_attr_list_copy(self.to_glib_none().0))
+        }
+    }
+
+    pub fn splice(&self, other: &AttrList, pos: i32, len: i32) {
+        unsafe {
+            ffi::pango_attr_list_splice(self.to_glib_none().0, other.to_glib_none().0, pos, len);
+        }
+    }
+}
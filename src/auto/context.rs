@@ -2,12 +2,19 @@
 // DO NOT EDIT
 
 use Direction;
+use Font;
 use FontDescription;
+use FontFamily;
 use FontMap;
+use FontMetrics;
+use Fontset;
 use Gravity;
 use GravityHint;
+use Language;
 use ffi;
 use glib::translate::*;
+use std::mem;
+use std::ptr;
 
 glib_wrapper! {
     pub struct Context(Object<ffi::PangoContext>);
@@ -67,17 +74,25 @@ impl Context {
         }
     }
 
-    //pub fn get_language(&self) -> /*Ignored*/Option<Language> {
-    //    unsafe { TODO: call ffi::pango_context_get_language() }
-    //}
+    pub fn get_language(&self) -> Option<Language> {
+        unsafe {
+            from_glib_none(ffi::pango_context_get_language(self.to_glib_none().0))
+        }
+    }
 
     //pub fn get_matrix(&self) -> /*Ignored*/Option<Matrix> {
     //    unsafe { TODO: call ffi::pango_context_get_matrix() }
     //}
 
-    //pub fn get_metrics<'a, 'b, P: Into<Option<&'a FontDescription>>, Q: Into<Option<&'b /*Ignored*/Language>>>(&self, desc: P, language: Q) -> /*Ignored*/Option<FontMetrics> {
-    //    unsafe { TODO: call ffi::pango_context_get_metrics() }
-    //}
+    pub fn get_metrics<'a, 'b, P: Into<Option<&'a FontDescription>>, Q: Into<Option<&'b Language>>>(&self, desc: P, language: Q) -> Option<FontMetrics> {
+        let desc = desc.into();
+        let desc = desc.to_glib_none();
+        let language = language.into();
+        let language = language.to_glib_none();
+        unsafe {
+            from_glib_full(ffi::pango_context_get_metrics(self.to_glib_none().0, desc.0, language.0))
+        }
+    }
 
     #[cfg(feature = "v1_32_4")]
     pub fn get_serial(&self) -> u32 {
@@ -86,17 +101,26 @@ impl Context {
         }
     }
 
-    //pub fn list_families(&self, families: /*Unimplemented*/Vec<FontFamily>) -> i32 {
-    //    unsafe { TODO: call ffi::pango_context_list_families() }
-    //}
+    pub fn list_families(&self) -> Vec<FontFamily> {
+        unsafe {
+            let mut families = ptr::null_mut();
+            let mut n_families = mem::uninitialized();
+            ffi::pango_context_list_families(self.to_glib_none().0, &mut families, &mut n_families);
+            FromGlibContainer::from_glib_container_num(families, n_families as usize)
+        }
+    }
 
-    //pub fn load_font(&self, desc: &FontDescription) -> /*Ignored*/Option<Font> {
-    //    unsafe { TODO: call ffi::pango_context_load_font() }
-    //}
+    pub fn load_font(&self, desc: &FontDescription) -> Option<Font> {
+        unsafe {
+            from_glib_full(ffi::pango_context_load_font(self.to_glib_none().0, desc.to_glib_none().0))
+        }
+    }
 
-    //pub fn load_fontset(&self, desc: &FontDescription, language: /*Ignored*/&mut Language) -> /*Ignored*/Option<Fontset> {
-    //    unsafe { TODO: call ffi::pango_context_load_fontset() }
-    //}
+    pub fn load_fontset(&self, desc: &FontDescription, language: &Language) -> Option<Fontset> {
+        unsafe {
+            from_glib_full(ffi::pango_context_load_fontset(self.to_glib_none().0, desc.to_glib_none().0, language.to_glib_none().0))
+        }
+    }
 
     pub fn set_base_dir(&self, direction: Direction) {
         unsafe {
@@ -128,9 +152,11 @@ impl Context {
         }
     }
 
-    //pub fn set_language(&self, language: /*Ignored*/&mut Language) {
-    //    unsafe { TODO: call ffi::pango_context_set_language() }
-    //}
+    pub fn set_language(&self, language: &Language) {
+        unsafe {
+            ffi::pango_context_set_language(self.to_glib_none().0, language.to_glib_none().0);
+        }
+    }
 
     //pub fn set_matrix<'a, P: Into<Option<&'a /*Ignored*/Matrix>>>(&self, matrix: P) {
     //    unsafe { TODO: call ffi::pango_context_set_matrix() }
@@ -120,6 +120,43 @@ impl FromGlib<ffi::PangoBidiType> for BidiType {
     }
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum CoverageLevel {
+    None,
+    Fallback,
+    Approximate,
+    Exact,
+    #[doc(hidden)]
+    __Nonexhaustive(()),
+}
+
+#[doc(hidden)]
+impl ToGlib for CoverageLevel {
+    type GlibType = ffi::PangoCoverageLevel;
+
+    fn to_glib(&self) -> ffi::PangoCoverageLevel {
+        match *self {
+            CoverageLevel::None => ffi::PANGO_COVERAGE_NONE,
+            CoverageLevel::Fallback => ffi::PANGO_COVERAGE_FALLBACK,
+            CoverageLevel::Approximate => ffi::PANGO_COVERAGE_APPROXIMATE,
+            CoverageLevel::Exact => ffi::PANGO_COVERAGE_EXACT,
+            CoverageLevel::__Nonexhaustive(_) => panic!(),
+        }
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::PangoCoverageLevel> for CoverageLevel {
+    fn from_glib(value: ffi::PangoCoverageLevel) -> Self {
+        match value {
+            ffi::PANGO_COVERAGE_NONE => CoverageLevel::None,
+            ffi::PANGO_COVERAGE_FALLBACK => CoverageLevel::Fallback,
+            ffi::PANGO_COVERAGE_APPROXIMATE => CoverageLevel::Approximate,
+            ffi::PANGO_COVERAGE_EXACT => CoverageLevel::Exact,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Direction {
     Ltr,
@@ -277,6 +314,43 @@ impl FromGlib<ffi::PangoGravityHint> for GravityHint {
     }
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum RenderPart {
+    Foreground,
+    Background,
+    Underline,
+    Strikethrough,
+    #[doc(hidden)]
+    __Nonexhaustive(()),
+}
+
+#[doc(hidden)]
+impl ToGlib for RenderPart {
+    type GlibType = ffi::PangoRenderPart;
+
+    fn to_glib(&self) -> ffi::PangoRenderPart {
+        match *self {
+            RenderPart::Foreground => ffi::PANGO_RENDER_PART_FOREGROUND,
+            RenderPart::Background => ffi::PANGO_RENDER_PART_BACKGROUND,
+            RenderPart::Underline => ffi::PANGO_RENDER_PART_UNDERLINE,
+            RenderPart::Strikethrough => ffi::PANGO_RENDER_PART_STRIKETHROUGH,
+            RenderPart::__Nonexhaustive(_) => panic!(),
+        }
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::PangoRenderPart> for RenderPart {
+    fn from_glib(value: ffi::PangoRenderPart) -> Self {
+        match value {
+            ffi::PANGO_RENDER_PART_FOREGROUND => RenderPart::Foreground,
+            ffi::PANGO_RENDER_PART_BACKGROUND => RenderPart::Background,
+            ffi::PANGO_RENDER_PART_UNDERLINE => RenderPart::Underline,
+            ffi::PANGO_RENDER_PART_STRIKETHROUGH => RenderPart::Strikethrough,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Script {
     InvalidCode,
@@ -742,6 +816,34 @@ impl FromGlib<ffi::PangoStyle> for Style {
     }
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TabAlign {
+    Left,
+    #[doc(hidden)]
+    __Nonexhaustive(()),
+}
+
+#[doc(hidden)]
+impl ToGlib for TabAlign {
+    type GlibType = ffi::PangoTabAlign;
+
+    fn to_glib(&self) -> ffi::PangoTabAlign {
+        match *self {
+            TabAlign::Left => ffi::PANGO_TAB_LEFT,
+            TabAlign::__Nonexhaustive(_) => panic!(),
+        }
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::PangoTabAlign> for TabAlign {
+    fn from_glib(value: ffi::PangoTabAlign) -> Self {
+        match value {
+            ffi::PANGO_TAB_LEFT => TabAlign::Left,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Underline {
     None,
@@ -31,8 +31,4 @@ impl FontFace {
             from_glib(ffi::pango_font_face_is_synthesized(self.to_glib_none().0))
         }
     }
-
-    //pub fn list_sizes(&self, sizes: /*Unimplemented*/Option<CArray TypeId { ns_id: 0, id: 14 }>) -> i32 {
-    //    unsafe { TODO: call ffi::pango_font_face_list_sizes() }
-    //}
 }
@@ -1,8 +1,11 @@
 // This file was generated by gir (32b0f11) from gir-files (71d73f0)
 // DO NOT EDIT
 
+use FontFace;
 use ffi;
 use glib::translate::*;
+use std::mem;
+use std::ptr;
 
 glib_wrapper! {
     pub struct FontFamily(Object<ffi::PangoFontFamily>);
@@ -25,7 +28,12 @@ impl FontFamily {
         }
     }
 
-    //pub fn list_faces(&self, faces: /*Unimplemented*/Vec<FontFace>) -> i32 {
-    //    unsafe { TODO: call ffi::pango_font_family_list_faces() }
-    //}
+    pub fn list_faces(&self) -> Vec<FontFace> {
+        unsafe {
+            let mut faces = ptr::null_mut();
+            let mut n_faces = mem::uninitialized();
+            ffi::pango_font_family_list_faces(self.to_glib_none().0, &mut faces, &mut n_faces);
+            FromGlibContainer::from_glib_container_num(faces, n_faces as usize)
+        }
+    }
 }
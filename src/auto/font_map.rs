@@ -2,9 +2,16 @@
 // DO NOT EDIT
 
 use Context;
+use Font;
+use FontDescription;
+use FontFamily;
+use Fontset;
+use Language;
 use ffi;
 use glib::object::IsA;
 use glib::translate::*;
+use std::mem;
+use std::ptr;
 
 glib_wrapper! {
     pub struct FontMap(Object<ffi::PangoFontMap>);
@@ -25,11 +32,11 @@ pub trait FontMapExt {
 
     fn get_shape_engine_type(&self) -> Option<String>;
 
-    //fn list_families(&self, families: /*Unimplemented*/Vec<FontFamily>) -> i32;
+    fn list_families(&self) -> Vec<FontFamily>;
 
-    //fn load_font(&self, context: &Context, desc: &FontDescription) -> /*Ignored*/Option<Font>;
+    fn load_font(&self, context: &Context, desc: &FontDescription) -> Option<Font>;
 
-    //fn load_fontset(&self, context: &Context, desc: &FontDescription, language: /*Ignored*/&mut Language) -> /*Ignored*/Option<Fontset>;
+    fn load_fontset(&self, context: &Context, desc: &FontDescription, language: &Language) -> Option<Fontset>;
 }
 
 impl<O: IsA<FontMap>> FontMapExt for O {
@@ -59,15 +66,24 @@ impl<O: IsA<FontMap>> FontMapExt for O {
         }
     }
 
-    //fn list_families(&self, families: /*Unimplemented*/Vec<FontFamily>) -> i32 {
-    //    unsafe { TODO: call ffi::pango_font_map_list_families() }
-    //}
+    fn list_families(&self) -> Vec<FontFamily> {
+        unsafe {
+            let mut families = ptr::null_mut();
+            let mut n_families = mem::uninitialized();
+            ffi::pango_font_map_list_families(self.to_glib_none().0, &mut families, &mut n_families);
+            FromGlibContainer::from_glib_container_num(families, n_families as usize)
+        }
+    }
 
-    //fn load_font(&self, context: &Context, desc: &FontDescription) -> /*Ignored*/Option<Font> {
-    //    unsafe { TODO: call ffi::pango_font_map_load_font() }
-    //}
+    fn load_font(&self, context: &Context, desc: &FontDescription) -> Option<Font> {
+        unsafe {
+            from_glib_full(ffi::pango_font_map_load_font(self.to_glib_none().0, context.to_glib_none().0, desc.to_glib_none().0))
+        }
+    }
 
-    //fn load_fontset(&self, context: &Context, desc: &FontDescription, language: /*Ignored*/&mut Language) -> /*Ignored*/Option<Fontset> {
-    //    unsafe { TODO: call ffi::pango_font_map_load_fontset() }
-    //}
+    fn load_fontset(&self, context: &Context, desc: &FontDescription, language: &Language) -> Option<Fontset> {
+        unsafe {
+            from_glib_full(ffi::pango_font_map_load_fontset(self.to_glib_none().0, context.to_glib_none().0, desc.to_glib_none().0, language.to_glib_none().0))
+        }
+    }
 }
@@ -0,0 +1,36 @@
+// This file was generated by gir (32b0f11) from gir-files (71d73f0)
+// DO NOT EDIT
+
+use Font;
+use FontMetrics;
+use ffi;
+use glib::object::IsA;
+use glib::translate::*;
+
+glib_wrapper! {
+    pub struct Fontset(Object<ffi::PangoFontset>);
+
+    match fn {
+        get_type => || ffi::pango_fontset_get_type(),
+    }
+}
+
+pub trait FontsetExt {
+    fn get_font(&self, wc: u32) -> Option<Font>;
+
+    fn get_metrics(&self) -> Option<FontMetrics>;
+}
+
+impl<O: IsA<Fontset>> FontsetExt for O {
+    fn get_font(&self, wc: u32) -> Option<Font> {
+        unsafe {
+            from_glib_full(ffi::pango_fontset_get_font(self.to_glib_none().0, wc))
+        }
+    }
+
+    fn get_metrics(&self) -> Option<FontMetrics> {
+        unsafe {
+            from_glib_full(ffi::pango_fontset_get_metrics(self.to_glib_none().0))
+        }
+    }
+}
@@ -0,0 +1,14 @@
+// This file was generated by gir (32b0f11) from gir-files (71d73f0)
+// DO NOT EDIT
+
+use ffi;
+use glib::translate::*;
+
+glib_wrapper! {
+    pub struct GlyphItem(Boxed<ffi::PangoGlyphItem>);
+
+    match fn {
+        copy => |ptr| ffi::pango_glyph_item_copy(mut_override(ptr)),
+        free => |ptr| ffi::pango_glyph_item_free(ptr),
+    }
+}
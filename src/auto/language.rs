@@ -1,7 +0,0 @@
-// This file was generated by gir (24767f3) from gir-files (71d73f0)
-// DO NOT EDIT
-
-use Script;
-use ffi;
-use glib::translate::*;
-use std::fmt;
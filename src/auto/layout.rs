@@ -2,12 +2,14 @@
 // DO NOT EDIT
 
 use Alignment;
+use AttrList;
 use Context;
 use EllipsizeMode;
 use FontDescription;
 use LayoutIter;
 use LayoutLine;
 use Rectangle;
+use TabArray;
 use WrapMode;
 use ffi;
 use glib::translate::*;
@@ -46,9 +48,11 @@ impl Layout {
         }
     }
 
-    //pub fn get_attributes(&self) -> /*Ignored*/Option<AttrList> {
-    //    unsafe { TODO: call ffi::pango_layout_get_attributes() }
-    //}
+    pub fn get_attributes(&self) -> Option<AttrList> {
+        unsafe {
+            from_glib_none(ffi::pango_layout_get_attributes(self.to_glib_none().0))
+        }
+    }
 
     pub fn get_auto_dir(&self) -> bool {
         unsafe {
@@ -158,14 +162,6 @@ impl Layout {
         }
     }
 
-    //pub fn get_log_attrs(&self, attrs: /*Unimplemented*/Vec<LogAttr>) -> i32 {
-    //    unsafe { TODO: call ffi::pango_layout_get_log_attrs() }
-    //}
-
-    //pub fn get_log_attrs_readonly(&self) -> (/*Ignored*/Vec<LogAttr>, i32) {
-    //    unsafe { TODO: call ffi::pango_layout_get_log_attrs_readonly() }
-    //}
-
     pub fn get_pixel_extents(&self) -> (Rectangle, Rectangle) {
         unsafe {
             let mut ink_rect = Rectangle::uninitialized();
@@ -212,9 +208,11 @@ impl Layout {
         }
     }
 
-    //pub fn get_tabs(&self) -> /*Ignored*/Option<TabArray> {
-    //    unsafe { TODO: call ffi::pango_layout_get_tabs() }
-    //}
+    pub fn get_tabs(&self) -> Option<TabArray> {
+        unsafe {
+            from_glib_full(ffi::pango_layout_get_tabs(self.to_glib_none().0))
+        }
+    }
 
     pub fn get_text(&self) -> Option<String> {
         unsafe {
@@ -284,9 +282,13 @@ impl Layout {
         }
     }
 
-    //pub fn set_attributes<'a, P: Into<Option<&'a /*Ignored*/AttrList>>>(&self, attrs: P) {
-    //    unsafe { TODO: call ffi::pango_layout_set_attributes() }
-    //}
+    pub fn set_attributes<'a, P: Into<Option<&'a AttrList>>>(&self, attrs: P) {
+        let attrs = attrs.into();
+        let attrs = attrs.to_glib_none();
+        unsafe {
+            ffi::pango_layout_set_attributes(self.to_glib_none().0, attrs.0);
+        }
+    }
 
     pub fn set_auto_dir(&self, auto_dir: bool) {
         unsafe {
@@ -352,9 +354,13 @@ impl Layout {
         }
     }
 
-    //pub fn set_tabs<'a, P: Into<Option<&'a /*Ignored*/TabArray>>>(&self, tabs: P) {
-    //    unsafe { TODO: call ffi::pango_layout_set_tabs() }
-    //}
+    pub fn set_tabs<'a, P: Into<Option<&'a TabArray>>>(&self, tabs: P) {
+        let tabs = tabs.into();
+        let tabs = tabs.to_glib_none();
+        unsafe {
+            ffi::pango_layout_set_tabs(self.to_glib_none().0, mut_override(tabs.0));
+        }
+    }
 
     pub fn set_text(&self, text: &str, length: i32) {
         unsafe {
@@ -3,6 +3,7 @@
 
 use Layout;
 use LayoutLine;
+use LayoutRun;
 use Rectangle;
 use ffi;
 use glib::translate::*;
@@ -98,9 +99,11 @@ impl LayoutIter {
         }
     }
 
-    //pub fn get_run(&mut self) -> /*Ignored*/Option<LayoutRun> {
-    //    unsafe { TODO: call ffi::pango_layout_iter_get_run() }
-    //}
+    pub fn get_run(&mut self) -> Option<LayoutRun> {
+        unsafe {
+            from_glib_none(ffi::pango_layout_iter_get_run(self.to_glib_none_mut().0))
+        }
+    }
 
     pub fn get_run_extents(&mut self) -> (Rectangle, Rectangle) {
         unsafe {
@@ -111,9 +114,11 @@ impl LayoutIter {
         }
     }
 
-    //pub fn get_run_readonly(&mut self) -> /*Ignored*/Option<LayoutRun> {
-    //    unsafe { TODO: call ffi::pango_layout_iter_get_run_readonly() }
-    //}
+    pub fn get_run_readonly(&mut self) -> Option<LayoutRun> {
+        unsafe {
+            from_glib_none(ffi::pango_layout_iter_get_run_readonly(self.to_glib_none_mut().0))
+        }
+    }
 
     pub fn next_char(&mut self) -> bool {
         unsafe {
@@ -1,9 +1,19 @@
 // This file was generated by gir (32b0f11) from gir-files (71d73f0)
 // DO NOT EDIT
 
+mod attr_list;
+pub use self::attr_list::AttrList;
+
 mod context;
 pub use self::context::Context;
 
+mod coverage;
+pub use self::coverage::Coverage;
+
+mod font;
+pub use self::font::Font;
+pub use self::font::FontExt;
+
 mod font_face;
 pub use self::font_face::FontFace;
 
@@ -14,6 +24,16 @@ mod font_map;
 pub use self::font_map::FontMap;
 pub use self::font_map::FontMapExt;
 
+mod font_metrics;
+pub use self::font_metrics::FontMetrics;
+
+mod fontset;
+pub use self::fontset::Fontset;
+pub use self::fontset::FontsetExt;
+
+mod glyph_item;
+pub use self::glyph_item::GlyphItem;
+
 mod layout;
 pub use self::layout::Layout;
 
@@ -23,16 +43,26 @@ pub use self::layout_iter::LayoutIter;
 mod layout_line;
 pub use self::layout_line::LayoutLine;
 
+mod renderer;
+pub use self::renderer::Renderer;
+pub use self::renderer::RendererExt;
+
+mod tab_array;
+pub use self::tab_array::TabArray;
+
 mod enums;
 pub use self::enums::Alignment;
 pub use self::enums::BidiType;
+pub use self::enums::CoverageLevel;
 pub use self::enums::Direction;
 pub use self::enums::EllipsizeMode;
 pub use self::enums::Gravity;
 pub use self::enums::GravityHint;
+pub use self::enums::RenderPart;
 pub use self::enums::Script;
 pub use self::enums::Stretch;
 pub use self::enums::Style;
+pub use self::enums::TabAlign;
 pub use self::enums::Underline;
 pub use self::enums::Variant;
 pub use self::enums::Weight;
@@ -50,5 +80,8 @@ pub use self::flags::FONT_MASK_GRAVITY;
 
 #[doc(hidden)]
 pub mod traits {
+    pub use super::FontExt;
     pub use super::FontMapExt;
+    pub use super::FontsetExt;
+    pub use super::RendererExt;
 }
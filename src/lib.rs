@@ -2,24 +2,74 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
+extern crate glib_sys as glib_ffi;
+extern crate gobject_sys as gobject_ffi;
 extern crate pango_sys as ffi;
 #[macro_use]
 extern crate glib;
 #[macro_use]
 extern crate bitflags;
 extern crate libc;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
 
 mod auto;
 pub use auto::*;
 
 pub use self::widgets::{
+    AttrIterator,
+    AttrKind,
+    InvalidAttrKind,
+    Attribute,
+    Color,
     Item,
+    itemize,
+    itemize_with_base_dir,
+    reorder_items,
+    visual_order,
+    shape,
     Rectangle,
     Matrix,
     GlyphString,
+    GlyphGeometry,
+    GlyphInfo,
+    GLYPH_EMPTY,
+    GLYPH_UNKNOWN_FLAG,
+    Analysis,
+    ANALYSIS_FLAG_CENTERED_BASELINE,
+    ANALYSIS_FLAG_IS_ELLIPSIS,
+    LayoutRun,
+    ClusterInfo,
+    GlyphItemIter,
     FontDescription,
+    Language,
+    LayoutSnapshot,
+    LineSnapshot,
+    RunSnapshot,
+    GlyphSnapshot,
+    AttrSnapshot,
+    LogAttr,
+    parse_markup,
+    MarkupError,
+    Markup,
+    SpanAttrs,
+    InvalidSpanAttr,
+    escape_text,
+    RendererImpl,
+    RendererImplExt,
+    default_break,
+    get_log_attrs,
+    TextBreaks,
+    GlyphOutlines,
+    SvgExport,
 };
 
+#[cfg(feature = "v1_31")]
+pub use self::widgets::MarkupParser;
+#[cfg(feature = "v1_32")]
+pub use self::widgets::shape_full;
+
 pub use ffi::PANGO_SCALE as SCALE;
 
 pub mod widgets;
@@ -0,0 +1,116 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::mem;
+
+use ffi;
+use gobject_ffi;
+use glib::translate::*;
+
+use {
+    Attribute,
+    Font,
+    Gravity,
+    Language,
+    Script,
+};
+
+/// The baseline of the item is vertically centered, used for vertical text.
+pub const ANALYSIS_FLAG_CENTERED_BASELINE: u8 = 1 << 0;
+/// The item is the ellipsis inserted by the layout.
+pub const ANALYSIS_FLAG_IS_ELLIPSIS: u8 = 1 << 1;
+
+/// The segment properties Pango computed for an `Item`, needed to shape
+/// and measure its text.
+#[repr(C)]
+pub struct Analysis(ffi::PangoAnalysis);
+
+impl Analysis {
+    #[doc(hidden)]
+    pub unsafe fn from_glib_ptr<'a>(ptr: *const ffi::PangoAnalysis) -> &'a Analysis {
+        &*(ptr as *const Analysis)
+    }
+
+    #[doc(hidden)]
+    pub unsafe fn from_glib_ptr_mut<'a>(ptr: *mut ffi::PangoAnalysis) -> &'a mut Analysis {
+        &mut *(ptr as *mut Analysis)
+    }
+
+    #[doc(hidden)]
+    pub fn to_glib_ptr(&self) -> *mut ffi::PangoAnalysis {
+        &self.0 as *const ffi::PangoAnalysis as *mut _
+    }
+
+    /// The font the item is to be rendered with.
+    pub fn font(&self) -> Option<Font> {
+        unsafe { from_glib_none(self.0.font) }
+    }
+
+    pub fn set_font<'a, P: Into<Option<&'a Font>>>(&mut self, font: P) {
+        unsafe {
+            let old = self.0.font;
+            self.0.font = font.into().to_glib_full();
+            if !old.is_null() {
+                gobject_ffi::g_object_unref(old as *mut _);
+            }
+        }
+    }
+
+    /// The bidirectional embedding level, odd levels are right-to-left.
+    pub fn level(&self) -> u8 {
+        self.0.level
+    }
+
+    pub fn set_level(&mut self, level: u8) {
+        self.0.level = level;
+    }
+
+    pub fn gravity(&self) -> Gravity {
+        // Pango only ever stores valid enumeration values here.
+        unsafe { from_glib(mem::transmute::<i32, ffi::PangoGravity>(self.0.gravity as i32)) }
+    }
+
+    pub fn set_gravity(&mut self, gravity: Gravity) {
+        self.0.gravity = gravity.to_glib() as u8;
+    }
+
+    /// A combination of the `ANALYSIS_FLAG_*` constants.
+    pub fn flags(&self) -> u8 {
+        self.0.flags
+    }
+
+    pub fn set_flags(&mut self, flags: u8) {
+        self.0.flags = flags;
+    }
+
+    pub fn script(&self) -> Script {
+        unsafe { from_glib(mem::transmute::<i32, ffi::PangoScript>(self.0.script as i32)) }
+    }
+
+    pub fn set_script(&mut self, script: Script) {
+        self.0.script = script.to_glib() as u8;
+    }
+
+    pub fn language(&self) -> Option<Language> {
+        unsafe { from_glib_none(self.0.language) }
+    }
+
+    pub fn set_language<'a, P: Into<Option<&'a Language>>>(&mut self, language: P) {
+        self.0.language = language.into().to_glib_none().0;
+    }
+
+    /// Copies of the attributes that affect rendering but not itemization,
+    /// e.g. colors and underlines.
+    pub fn extra_attrs(&self) -> Vec<Attribute> {
+        let mut ret = Vec::new();
+        unsafe {
+            let mut l = self.0.extra_attrs;
+            while !l.is_null() {
+                ret.push(from_glib_none((*l).data as *const ffi::PangoAttribute));
+                l = (*l).next;
+            }
+        }
+        ret
+    }
+}
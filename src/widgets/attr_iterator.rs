@@ -0,0 +1,151 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::mem;
+use std::ptr;
+
+use ffi;
+use glib_ffi;
+use glib::translate::*;
+
+use {
+    AttrList,
+    Attribute,
+    FontDescription,
+    Language,
+};
+
+/// Walks the ranges of an `AttrList` over which the set of active
+/// attributes is constant.
+///
+/// As an `Iterator` it yields `(start_index, end_index, attributes)` for the
+/// current range and then advances. The last range ends at `i32::MAX`.
+///
+/// Created by the unsafe `AttrList::get_iterator()`, as the list must not
+/// change while it is iterated.
+pub struct AttrIterator {
+    pointer: *mut ffi::PangoAttrIterator,
+    // The C iterator does not keep its list alive.
+    list: AttrList,
+    finished: bool,
+}
+
+impl AttrIterator {
+    #[doc(hidden)]
+    pub unsafe fn new(pointer: *mut ffi::PangoAttrIterator, list: &AttrList) -> AttrIterator {
+        assert!(!pointer.is_null());
+        AttrIterator {
+            pointer: pointer,
+            list: list.clone(),
+            finished: false,
+        }
+    }
+
+    /// Moves to the next range. Returns `false` if the iterator was already
+    /// at the last one.
+    pub fn advance(&mut self) -> bool {
+        let ret = unsafe { from_glib(ffi::pango_attr_iterator_next(self.pointer)) };
+        if !ret {
+            self.finished = true;
+        }
+        ret
+    }
+
+    pub fn range(&self) -> (i32, i32) {
+        unsafe {
+            let mut start = mem::uninitialized();
+            let mut end = mem::uninitialized();
+            ffi::pango_attr_iterator_range(self.pointer, &mut start, &mut end);
+            (start, end)
+        }
+    }
+
+    /// Returns copies of all attributes active in the current range.
+    pub fn get_attrs(&self) -> Vec<Attribute> {
+        unsafe { attributes_from_slist_full(ffi::pango_attr_iterator_get_attrs(self.pointer)) }
+    }
+
+    /// Resolves the font description, language and remaining non-font
+    /// attributes in effect for the current range.
+    pub fn get_font(&self) -> (FontDescription, Option<Language>, Vec<Attribute>) {
+        unsafe {
+            let mut desc = FontDescription::new();
+            let mut language = ptr::null_mut();
+            let mut extra_attrs = ptr::null_mut();
+            ffi::pango_attr_iterator_get_font(self.pointer, (&desc).to_glib_none().0, &mut language, &mut extra_attrs);
+            // The family is set with `set_family_static()` and points into
+            // the attribute list, take a copy so the description is standalone.
+            if let Some(family) = desc.get_family() {
+                desc.set_family(&family);
+            }
+            (desc, from_glib_none(language), attributes_from_slist_full(extra_attrs))
+        }
+    }
+
+    pub fn get_attr_list(&self) -> &AttrList {
+        &self.list
+    }
+
+    // Pango moved the iterator to a range that was not yielded yet, e.g.
+    // in `pango_itemize()`.
+    #[doc(hidden)]
+    pub fn reset_finished(&mut self) {
+        self.finished = false;
+    }
+}
+
+impl Iterator for AttrIterator {
+    type Item = (i32, i32, Vec<Attribute>);
+
+    fn next(&mut self) -> Option<(i32, i32, Vec<Attribute>)> {
+        if self.finished {
+            return None;
+        }
+
+        let (start, end) = self.range();
+        let attrs = self.get_attrs();
+        self.advance();
+        Some((start, end, attrs))
+    }
+}
+
+impl Clone for AttrIterator {
+    fn clone(&self) -> AttrIterator {
+        unsafe {
+            let tmp = ffi::pango_attr_iterator_copy(self.pointer);
+            assert!(!tmp.is_null());
+            AttrIterator {
+                pointer: tmp,
+                list: self.list.clone(),
+                finished: self.finished,
+            }
+        }
+    }
+}
+
+impl Drop for AttrIterator {
+    fn drop(&mut self) {
+        unsafe { ffi::pango_attr_iterator_destroy(self.pointer) }
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtrMut<'a, *mut ffi::PangoAttrIterator> for AttrIterator {
+    type Storage = &'a mut Self;
+
+    fn to_glib_none_mut(&'a mut self) -> StashMut<'a, *mut ffi::PangoAttrIterator, Self> {
+        StashMut(self.pointer, self)
+    }
+}
+
+unsafe fn attributes_from_slist_full(list: *mut glib_ffi::GSList) -> Vec<Attribute> {
+    let mut ret = Vec::new();
+    let mut l = list;
+    while !l.is_null() {
+        ret.push(from_glib_full((*l).data as *mut ffi::PangoAttribute));
+        l = (*l).next;
+    }
+    glib_ffi::g_slist_free(list);
+    ret
+}
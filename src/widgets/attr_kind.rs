@@ -0,0 +1,338 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::error;
+use std::fmt;
+
+use ffi;
+use glib::translate::*;
+
+use {
+    Attribute,
+    Color,
+    FontDescription,
+    Gravity,
+    GravityHint,
+    Language,
+    Rectangle,
+    Stretch,
+    Style,
+    Underline,
+    Variant,
+    Weight,
+};
+
+/// The type and value of an `Attribute`.
+///
+/// Converting an `AttrKind` into an `Attribute` with `to_attribute()` and
+/// back yields the same value. The byte range the attribute applies to is
+/// kept on the `Attribute` itself.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttrKind {
+    Language(Language),
+    Family(String),
+    Style(Style),
+    Weight(Weight),
+    /// A weight without a named `Weight` value, e.g. 550. Any value between
+    /// 100 and 1000 is valid.
+    WeightValue(i32),
+    Variant(Variant),
+    Stretch(Stretch),
+    /// Font size in Pango units.
+    Size(i32),
+    FontDesc(FontDescription),
+    Foreground(Color),
+    Background(Color),
+    Underline(Underline),
+    Strikethrough(bool),
+    /// Baseline displacement in Pango units.
+    Rise(i32),
+    /// Replaces the text with a logical box, any user data attached to the
+    /// C attribute is not represented.
+    Shape {
+        ink_rect: Rectangle,
+        logical_rect: Rectangle,
+    },
+    Scale(f64),
+    Fallback(bool),
+    /// Extra space between graphemes in Pango units.
+    LetterSpacing(i32),
+    UnderlineColor(Color),
+    StrikethroughColor(Color),
+    /// Font size in device units.
+    AbsoluteSize(i32),
+    Gravity(Gravity),
+    GravityHint(GravityHint),
+    /// OpenType features in CSS syntax, e.g. `"smcp, liga=0"`.
+    #[cfg(feature = "v1_38")]
+    FontFeatures(String),
+    #[cfg(feature = "v1_38")]
+    ForegroundAlpha(u16),
+    #[cfg(feature = "v1_38")]
+    BackgroundAlpha(u16),
+    /// An enumeration attribute (style, variant, stretch, underline,
+    /// gravity or gravity hint) whose value has no Rust variant, e.g. one
+    /// added by a newer Pango. `type_` is the `PangoAttrType`.
+    Other {
+        type_: i32,
+        value: i32,
+    },
+}
+
+impl AttrKind {
+    /// Creates an attribute of this type and value, which fails for values
+    /// Pango can't represent: `__Nonexhaustive` enumeration values,
+    /// `Gravity::Auto` and `Other` with a type that isn't an enumeration.
+    pub fn to_attribute(&self) -> Result<Attribute, InvalidAttrKind> {
+        let attr = match *self {
+            AttrKind::Language(ref language) => Attribute::new_language(language),
+            AttrKind::Family(ref family) => Attribute::new_family(family),
+            AttrKind::Style(style) if style != Style::__Nonexhaustive(()) => Attribute::new_style(style),
+            AttrKind::Weight(weight) if weight != Weight::__Nonexhaustive(()) => Attribute::new_weight(weight),
+            AttrKind::WeightValue(value) => with_int_value(Attribute::new_weight(Weight::Normal), value),
+            AttrKind::Variant(variant) if variant != Variant::__Nonexhaustive(()) => Attribute::new_variant(variant),
+            AttrKind::Stretch(stretch) if stretch != Stretch::__Nonexhaustive(()) => Attribute::new_stretch(stretch),
+            AttrKind::Size(size) => Attribute::new_size(size),
+            AttrKind::FontDesc(ref desc) => Attribute::new_font_desc(desc),
+            AttrKind::Foreground(c) => Attribute::new_foreground(c.red, c.green, c.blue),
+            AttrKind::Background(c) => Attribute::new_background(c.red, c.green, c.blue),
+            AttrKind::Underline(underline) if underline != Underline::__Nonexhaustive(()) => {
+                Attribute::new_underline(underline)
+            }
+            AttrKind::Strikethrough(strikethrough) => Attribute::new_strikethrough(strikethrough),
+            AttrKind::Rise(rise) => Attribute::new_rise(rise),
+            AttrKind::Shape { ref ink_rect, ref logical_rect } => Attribute::new_shape(ink_rect, logical_rect),
+            AttrKind::Scale(scale) => Attribute::new_scale(scale),
+            AttrKind::Fallback(fallback) => Attribute::new_fallback(fallback),
+            AttrKind::LetterSpacing(spacing) => Attribute::new_letter_spacing(spacing),
+            AttrKind::UnderlineColor(c) => Attribute::new_underline_color(c.red, c.green, c.blue),
+            AttrKind::StrikethroughColor(c) => Attribute::new_strikethrough_color(c.red, c.green, c.blue),
+            AttrKind::AbsoluteSize(size) => Attribute::new_absolute_size(size),
+            // Pango doesn't create gravity attributes with `Auto`.
+            AttrKind::Gravity(gravity) if gravity != Gravity::Auto && gravity != Gravity::__Nonexhaustive(()) => {
+                Attribute::new_gravity(gravity)
+            }
+            AttrKind::GravityHint(hint) if hint != GravityHint::__Nonexhaustive(()) => {
+                Attribute::new_gravity_hint(hint)
+            }
+            #[cfg(feature = "v1_38")]
+            AttrKind::FontFeatures(ref features) => Attribute::new_font_features(features),
+            #[cfg(feature = "v1_38")]
+            AttrKind::ForegroundAlpha(alpha) => Attribute::new_foreground_alpha(alpha),
+            #[cfg(feature = "v1_38")]
+            AttrKind::BackgroundAlpha(alpha) => Attribute::new_background_alpha(alpha),
+            AttrKind::Other { type_, value } => {
+                let attr = if type_ == ffi::PANGO_ATTR_STYLE as i32 {
+                    Attribute::new_style(Style::Normal)
+                } else if type_ == ffi::PANGO_ATTR_VARIANT as i32 {
+                    Attribute::new_variant(Variant::Normal)
+                } else if type_ == ffi::PANGO_ATTR_STRETCH as i32 {
+                    Attribute::new_stretch(Stretch::Normal)
+                } else if type_ == ffi::PANGO_ATTR_UNDERLINE as i32 {
+                    Attribute::new_underline(Underline::None)
+                } else if type_ == ffi::PANGO_ATTR_GRAVITY as i32 {
+                    Attribute::new_gravity(Gravity::South)
+                } else if type_ == ffi::PANGO_ATTR_GRAVITY_HINT as i32 {
+                    Attribute::new_gravity_hint(GravityHint::Natural)
+                } else {
+                    return Err(InvalidAttrKind(self.clone()));
+                };
+                with_int_value(attr, value)
+            }
+            _ => return Err(InvalidAttrKind(self.clone())),
+        };
+        Ok(attr)
+    }
+}
+
+/// Returned by `AttrKind::to_attribute()` for a value that has no
+/// `Attribute` representation. Holds the rejected value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidAttrKind(pub AttrKind);
+
+impl fmt::Display for InvalidAttrKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no attribute can represent {:?}", self.0)
+    }
+}
+
+impl error::Error for InvalidAttrKind {
+    fn description(&self) -> &str {
+        "invalid attribute value"
+    }
+}
+
+impl Attribute {
+    /// Returns the typed value of the attribute, or `None` for attribute
+    /// types registered outside of Pango or unknown to these bindings.
+    ///
+    /// Enumeration values without a matching Rust variant are returned as
+    /// `AttrKind::WeightValue` or `AttrKind::Other`.
+    pub fn kind(&self) -> Option<AttrKind> {
+        unsafe {
+            let attr: *const ffi::PangoAttribute = self.to_glib_none().0;
+            match (*(*attr).klass).type_ {
+                ffi::PANGO_ATTR_LANGUAGE => {
+                    let attr = attr as *const ffi::PangoAttrLanguage;
+                    Some(AttrKind::Language(from_glib_none((*attr).value)))
+                }
+                ffi::PANGO_ATTR_FAMILY => Some(AttrKind::Family(string_value(attr))),
+                ffi::PANGO_ATTR_STYLE => {
+                    Some(style_from_int(int_value(attr)).map_or_else(|| other(attr), AttrKind::Style))
+                }
+                ffi::PANGO_ATTR_WEIGHT => {
+                    let value = int_value(attr);
+                    Some(weight_from_int(value).map_or(AttrKind::WeightValue(value), AttrKind::Weight))
+                }
+                ffi::PANGO_ATTR_VARIANT => {
+                    Some(variant_from_int(int_value(attr)).map_or_else(|| other(attr), AttrKind::Variant))
+                }
+                ffi::PANGO_ATTR_STRETCH => {
+                    Some(stretch_from_int(int_value(attr)).map_or_else(|| other(attr), AttrKind::Stretch))
+                }
+                ffi::PANGO_ATTR_SIZE => Some(AttrKind::Size(size_value(attr))),
+                ffi::PANGO_ATTR_FONT_DESC => {
+                    let attr = attr as *const ffi::PangoAttrFontDesc;
+                    Some(AttrKind::FontDesc(from_glib_none((*attr).desc)))
+                }
+                ffi::PANGO_ATTR_FOREGROUND => Some(AttrKind::Foreground(color_value(attr))),
+                ffi::PANGO_ATTR_BACKGROUND => Some(AttrKind::Background(color_value(attr))),
+                ffi::PANGO_ATTR_UNDERLINE => {
+                    Some(underline_from_int(int_value(attr)).map_or_else(|| other(attr), AttrKind::Underline))
+                }
+                ffi::PANGO_ATTR_STRIKETHROUGH => Some(AttrKind::Strikethrough(int_value(attr) != 0)),
+                ffi::PANGO_ATTR_RISE => Some(AttrKind::Rise(int_value(attr))),
+                ffi::PANGO_ATTR_SHAPE => {
+                    let attr = attr as *const ffi::PangoAttrShape;
+                    Some(AttrKind::Shape {
+                        ink_rect: from_glib_none(&(*attr).ink_rect as *const _),
+                        logical_rect: from_glib_none(&(*attr).logical_rect as *const _),
+                    })
+                }
+                ffi::PANGO_ATTR_SCALE => {
+                    let attr = attr as *const ffi::PangoAttrFloat;
+                    Some(AttrKind::Scale((*attr).value))
+                }
+                ffi::PANGO_ATTR_FALLBACK => Some(AttrKind::Fallback(int_value(attr) != 0)),
+                ffi::PANGO_ATTR_LETTER_SPACING => Some(AttrKind::LetterSpacing(int_value(attr))),
+                ffi::PANGO_ATTR_UNDERLINE_COLOR => Some(AttrKind::UnderlineColor(color_value(attr))),
+                ffi::PANGO_ATTR_STRIKETHROUGH_COLOR => Some(AttrKind::StrikethroughColor(color_value(attr))),
+                ffi::PANGO_ATTR_ABSOLUTE_SIZE => Some(AttrKind::AbsoluteSize(size_value(attr))),
+                ffi::PANGO_ATTR_GRAVITY => {
+                    Some(gravity_from_int(int_value(attr)).map_or_else(|| other(attr), AttrKind::Gravity))
+                }
+                ffi::PANGO_ATTR_GRAVITY_HINT => {
+                    Some(gravity_hint_from_int(int_value(attr)).map_or_else(|| other(attr), AttrKind::GravityHint))
+                }
+                #[cfg(feature = "v1_38")]
+                ffi::PANGO_ATTR_FONT_FEATURES => {
+                    let attr = attr as *const ffi::PangoAttrFontFeatures;
+                    Some(AttrKind::FontFeatures(from_glib_none((*attr).features)))
+                }
+                #[cfg(feature = "v1_38")]
+                ffi::PANGO_ATTR_FOREGROUND_ALPHA => Some(AttrKind::ForegroundAlpha(int_value(attr) as u16)),
+                #[cfg(feature = "v1_38")]
+                ffi::PANGO_ATTR_BACKGROUND_ALPHA => Some(AttrKind::BackgroundAlpha(int_value(attr) as u16)),
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_shape(ink_rect: &Rectangle, logical_rect: &Rectangle) -> Attribute {
+        unsafe {
+            from_glib_full(ffi::pango_attr_shape_new(ink_rect.to_glib_none().0, logical_rect.to_glib_none().0))
+        }
+    }
+
+    #[cfg(feature = "v1_38")]
+    pub fn new_font_features(features: &str) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_font_features_new(features.to_glib_none().0)) }
+    }
+
+    #[cfg(feature = "v1_38")]
+    pub fn new_foreground_alpha(alpha: u16) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_foreground_alpha_new(alpha)) }
+    }
+
+    #[cfg(feature = "v1_38")]
+    pub fn new_background_alpha(alpha: u16) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_background_alpha_new(alpha)) }
+    }
+}
+
+unsafe fn int_value(attr: *const ffi::PangoAttribute) -> i32 {
+    (*(attr as *const ffi::PangoAttrInt)).value
+}
+
+unsafe fn other(attr: *const ffi::PangoAttribute) -> AttrKind {
+    AttrKind::Other {
+        type_: (*(*attr).klass).type_ as i32,
+        value: int_value(attr),
+    }
+}
+
+fn with_int_value(attr: Attribute, value: i32) -> Attribute {
+    unsafe {
+        let ptr: *mut ffi::PangoAttribute = (&attr).to_glib_none().0;
+        (*(ptr as *mut ffi::PangoAttrInt)).value = value;
+    }
+    attr
+}
+
+unsafe fn size_value(attr: *const ffi::PangoAttribute) -> i32 {
+    (*(attr as *const ffi::PangoAttrSize)).size
+}
+
+unsafe fn string_value(attr: *const ffi::PangoAttribute) -> String {
+    from_glib_none((*(attr as *const ffi::PangoAttrString)).value)
+}
+
+unsafe fn color_value(attr: *const ffi::PangoAttribute) -> Color {
+    from_glib_none(&(*(attr as *const ffi::PangoAttrColor)).color as *const _)
+}
+
+// Integer attributes may carry any value, only map those that have a
+// variant instead of going through `from_glib()`.
+macro_rules! enum_from_int {
+    ($name:ident, $ty:ident, [$($variant:ident),+]) => {
+        fn $name(value: i32) -> Option<$ty> {
+            [$($ty::$variant),+].iter().cloned().find(|v| v.to_glib() as i32 == value)
+        }
+    }
+}
+
+enum_from_int!(style_from_int, Style, [Normal, Oblique, Italic]);
+enum_from_int!(weight_from_int, Weight, [Thin, Ultralight, Light, Semilight, Book, Normal, Medium,
+                                          Semibold, Bold, Ultrabold, Heavy, Ultraheavy]);
+enum_from_int!(variant_from_int, Variant, [Normal, SmallCaps]);
+enum_from_int!(stretch_from_int, Stretch, [UltraCondensed, ExtraCondensed, Condensed, SemiCondensed,
+                                            Normal, SemiExpanded, Expanded, ExtraExpanded, UltraExpanded]);
+enum_from_int!(underline_from_int, Underline, [None, Single, Double, Low, Error]);
+enum_from_int!(gravity_from_int, Gravity, [South, East, North, West, Auto]);
+enum_from_int!(gravity_hint_from_int, GravityHint, [Natural, Strong, Line]);
+
+#[cfg(test)]
+mod tests {
+    use ffi;
+
+    use {Gravity, Weight};
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for kind in vec![AttrKind::Weight(Weight::Bold), AttrKind::WeightValue(550), AttrKind::Rise(-1024),
+                         AttrKind::Other { type_: ffi::PANGO_ATTR_STYLE as i32, value: 7 }] {
+            assert_eq!(kind.to_attribute().unwrap().kind(), Some(kind));
+        }
+    }
+
+    #[test]
+    fn unrepresentable_values() {
+        for kind in vec![AttrKind::Weight(Weight::__Nonexhaustive(())), AttrKind::Gravity(Gravity::Auto),
+                         AttrKind::Other { type_: ffi::PANGO_ATTR_RISE as i32, value: 0 },
+                         AttrKind::Other { type_: -1, value: 0 }] {
+            assert_eq!(kind.to_attribute().unwrap_err(), InvalidAttrKind(kind));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::mem::{self, ManuallyDrop};
+
+use ffi;
+use glib_ffi;
+use glib::translate::*;
+use libc::c_void;
+
+use AttrIterator;
+use AttrList;
+use Attribute;
+use super::callback::call_from_c;
+
+impl AttrList {
+    /// Inserts `attr` after all other attributes with a matching start index.
+    pub fn insert(&self, attr: Attribute) {
+        unsafe {
+            ffi::pango_attr_list_insert(self.to_glib_none().0, attr.to_glib_none().0);
+            // The list takes ownership of the attribute.
+            mem::forget(attr);
+        }
+    }
+
+    /// Inserts `attr` before all other attributes with a matching start index.
+    pub fn insert_before(&self, attr: Attribute) {
+        unsafe {
+            ffi::pango_attr_list_insert_before(self.to_glib_none().0, attr.to_glib_none().0);
+            mem::forget(attr);
+        }
+    }
+
+    /// Inserts `attr`, merging it with or replacing overlapping attributes
+    /// of the same type.
+    pub fn change(&self, attr: Attribute) {
+        unsafe {
+            ffi::pango_attr_list_change(self.to_glib_none().0, attr.to_glib_none().0);
+            mem::forget(attr);
+        }
+    }
+
+    /// Creates an iterator positioned at the first range of the list.
+    ///
+    /// # Safety
+    ///
+    /// The iterator keeps the list alive but not its attributes. The list
+    /// must not be modified through any handle, e.g. with `insert()`,
+    /// `change()`, `splice()` or `filter()`, while the iterator exists.
+    pub unsafe fn get_iterator(&self) -> AttrIterator {
+        AttrIterator::new(ffi::pango_attr_list_get_iterator(self.to_glib_none().0), self)
+    }
+
+    /// Removes every attribute for which `func` returns `true` and returns
+    /// them in a new list, or `None` if nothing matched.
+    ///
+    /// `func` is called from C code, a panic in it aborts the process.
+    pub fn filter<F: FnMut(&Attribute) -> bool>(&self, mut func: F) -> Option<AttrList> {
+        unsafe {
+            let mut func: &mut FnMut(&Attribute) -> bool = &mut func;
+            let data = &mut func as *mut &mut FnMut(&Attribute) -> bool as *mut c_void;
+            from_glib_full(ffi::pango_attr_list_filter(self.to_glib_none().0, Some(filter_trampoline), data))
+        }
+    }
+}
+
+impl Default for AttrList {
+    fn default() -> AttrList {
+        AttrList::new()
+    }
+}
+
+unsafe extern "C" fn filter_trampoline(attr: *mut ffi::PangoAttribute, data: glib_ffi::gpointer) -> glib_ffi::gboolean {
+    let func = &mut *(data as *mut &mut FnMut(&Attribute) -> bool);
+    // The list still owns the attribute, it must not be destroyed even if
+    // `func` panics.
+    let attr: ManuallyDrop<Attribute> = ManuallyDrop::new(from_glib_full(attr));
+    call_from_c(|| func(&attr)).to_glib()
+}
@@ -0,0 +1,189 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use ffi;
+use glib::translate::*;
+
+use {
+    FontDescription,
+    Gravity,
+    GravityHint,
+    Language,
+    Stretch,
+    Style,
+    Underline,
+    Variant,
+    Weight,
+};
+
+pub struct Attribute(*mut ffi::PangoAttribute);
+
+impl Attribute {
+    pub fn new_language(language: &Language) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_language_new(language.to_glib_none().0)) }
+    }
+
+    pub fn new_family(family: &str) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_family_new(family.to_glib_none().0)) }
+    }
+
+    pub fn new_foreground(red: u16, green: u16, blue: u16) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_foreground_new(red, green, blue)) }
+    }
+
+    pub fn new_background(red: u16, green: u16, blue: u16) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_background_new(red, green, blue)) }
+    }
+
+    pub fn new_weight(weight: Weight) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_weight_new(weight.to_glib())) }
+    }
+
+    pub fn new_style(style: Style) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_style_new(style.to_glib())) }
+    }
+
+    pub fn new_variant(variant: Variant) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_variant_new(variant.to_glib())) }
+    }
+
+    pub fn new_stretch(stretch: Stretch) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_stretch_new(stretch.to_glib())) }
+    }
+
+    /// Font size in Pango units (see `SCALE`).
+    pub fn new_size(size: i32) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_size_new(size)) }
+    }
+
+    /// Font size in device units, not scaled by the font matrix.
+    pub fn new_absolute_size(size: i32) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_size_new_absolute(size)) }
+    }
+
+    pub fn new_font_desc(desc: &FontDescription) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_font_desc_new(desc.to_glib_none().0)) }
+    }
+
+    pub fn new_underline(underline: Underline) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_underline_new(underline.to_glib())) }
+    }
+
+    pub fn new_underline_color(red: u16, green: u16, blue: u16) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_underline_color_new(red, green, blue)) }
+    }
+
+    pub fn new_strikethrough(strikethrough: bool) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_strikethrough_new(strikethrough.to_glib())) }
+    }
+
+    pub fn new_strikethrough_color(red: u16, green: u16, blue: u16) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_strikethrough_color_new(red, green, blue)) }
+    }
+
+    /// Baseline displacement in Pango units, positive values move the text up.
+    pub fn new_rise(rise: i32) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_rise_new(rise)) }
+    }
+
+    /// Extra space between graphemes in Pango units.
+    pub fn new_letter_spacing(letter_spacing: i32) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_letter_spacing_new(letter_spacing)) }
+    }
+
+    pub fn new_scale(scale_factor: f64) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_scale_new(scale_factor)) }
+    }
+
+    pub fn new_fallback(enable_fallback: bool) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_fallback_new(enable_fallback.to_glib())) }
+    }
+
+    pub fn new_gravity(gravity: Gravity) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_gravity_new(gravity.to_glib())) }
+    }
+
+    pub fn new_gravity_hint(hint: GravityHint) -> Attribute {
+        unsafe { from_glib_full(ffi::pango_attr_gravity_hint_new(hint.to_glib())) }
+    }
+
+    pub fn get_start_index(&self) -> u32 {
+        unsafe { (*self.0).start_index }
+    }
+
+    pub fn get_end_index(&self) -> u32 {
+        unsafe { (*self.0).end_index }
+    }
+
+    /// Restricts the attribute to the byte range `start_index..end_index` of
+    /// the text it is applied to. New attributes cover the whole text.
+    pub fn set_range(&mut self, start_index: u32, end_index: u32) {
+        unsafe {
+            (*self.0).start_index = start_index;
+            (*self.0).end_index = end_index;
+        }
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtr<'a, *const ffi::PangoAttribute> for &'a Attribute {
+    type Storage = &'a Attribute;
+
+    fn to_glib_none(&self) -> Stash<'a, *const ffi::PangoAttribute, Self> {
+        Stash(self.0 as *const _, *self)
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtr<'a, *mut ffi::PangoAttribute> for &'a Attribute {
+    type Storage = &'a Attribute;
+
+    fn to_glib_none(&self) -> Stash<'a, *mut ffi::PangoAttribute, Self> {
+        Stash(self.0, *self)
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrNone<*const ffi::PangoAttribute> for Attribute {
+    unsafe fn from_glib_none(ptr: *const ffi::PangoAttribute) -> Self {
+        let tmp = ffi::pango_attribute_copy(ptr);
+        assert!(!tmp.is_null());
+        Attribute(tmp)
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrNone<*mut ffi::PangoAttribute> for Attribute {
+    unsafe fn from_glib_none(ptr: *mut ffi::PangoAttribute) -> Self {
+        from_glib_none(ptr as *const ffi::PangoAttribute)
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrFull<*mut ffi::PangoAttribute> for Attribute {
+    unsafe fn from_glib_full(ptr: *mut ffi::PangoAttribute) -> Self {
+        assert!(!ptr.is_null());
+        Attribute(ptr)
+    }
+}
+
+impl PartialEq for Attribute {
+    fn eq(&self, other: &Attribute) -> bool {
+        unsafe { from_glib(ffi::pango_attribute_equal(self.0, other.0)) }
+    }
+}
+
+impl Eq for Attribute {}
+
+impl Clone for Attribute {
+    fn clone(&self) -> Attribute {
+        unsafe { from_glib_none(self.0 as *const ffi::PangoAttribute) }
+    }
+}
+
+impl Drop for Attribute {
+    fn drop(&mut self) {
+        unsafe { ffi::pango_attribute_destroy(self.0) }
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+
+/// Runs `f` on behalf of a callback from Pango's C code.
+///
+/// Unwinding into C frames is undefined behaviour, so a panic in `f`
+/// aborts the process instead.
+pub fn call_from_c<R, F: FnOnce() -> R>(f: F) -> R {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(ret) => ret,
+        Err(_) => process::abort(),
+    }
+}
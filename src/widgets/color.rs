@@ -0,0 +1,88 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::fmt;
+use std::mem;
+use glib::translate::*;
+use ffi;
+
+/// An RGB color with 16 bits per channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Color {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+}
+
+impl Color {
+    pub fn new(red: u16, green: u16, blue: u16) -> Color {
+        Color {
+            red: red,
+            green: green,
+            blue: blue,
+        }
+    }
+
+    /// Parses a color name such as `"red"` or a hexadecimal specification
+    /// such as `"#ff8000"`.
+    pub fn parse(spec: &str) -> Option<Color> {
+        unsafe {
+            let mut color = Color::uninitialized();
+            let ret = from_glib(ffi::pango_color_parse(color.to_glib_none_mut().0, spec.to_glib_none().0));
+            if ret { Some(color) } else { None }
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s: String = unsafe { from_glib_full(ffi::pango_color_to_string(self.to_glib_none().0)) };
+        write!(f, "{}", s)
+    }
+}
+
+#[doc(hidden)]
+impl Uninitialized for Color {
+    #[inline]
+    unsafe fn uninitialized() -> Self {
+        mem::uninitialized()
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtr<'a, *const ffi::PangoColor> for Color {
+    type Storage = &'a Self;
+
+    #[inline]
+    fn to_glib_none(&'a self) -> Stash<'a, *const ffi::PangoColor, Self> {
+        let ptr: *const Color = &*self;
+        Stash(ptr as *const ffi::PangoColor, self)
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtrMut<'a, *mut ffi::PangoColor> for Color {
+    type Storage = &'a mut Self;
+
+    #[inline]
+    fn to_glib_none_mut(&'a mut self) -> StashMut<'a, *mut ffi::PangoColor, Self> {
+        let ptr: *mut Color = &mut *self;
+        StashMut(ptr as *mut ffi::PangoColor, self)
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrNone<*const ffi::PangoColor> for Color {
+    unsafe fn from_glib_none(ptr: *const ffi::PangoColor) -> Self {
+        *(ptr as *const Color)
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrNone<*mut ffi::PangoColor> for Color {
+    unsafe fn from_glib_none(ptr: *mut ffi::PangoColor) -> Self {
+        *(ptr as *mut Color)
+    }
+}
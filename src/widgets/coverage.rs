@@ -0,0 +1,62 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::mem;
+use std::ptr;
+use std::slice;
+
+use ffi;
+use glib_ffi;
+use glib::translate::*;
+
+use Coverage;
+use CoverageLevel;
+use Font;
+use FontExt;
+use Language;
+
+impl Coverage {
+    /// Serializes the coverage map into Pango's compact binary format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        unsafe {
+            let mut bytes = ptr::null_mut();
+            let mut n_bytes = mem::uninitialized();
+            ffi::pango_coverage_to_bytes(self.to_glib_none().0, &mut bytes, &mut n_bytes);
+            let ret = slice::from_raw_parts(bytes, n_bytes as usize).to_vec();
+            glib_ffi::g_free(bytes as glib_ffi::gpointer);
+            ret
+        }
+    }
+
+    /// Deserializes data produced by `to_bytes()`, returns `None` if the
+    /// data is not valid.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Coverage> {
+        unsafe {
+            from_glib_full(ffi::pango_coverage_from_bytes(bytes.as_ptr() as *mut u8, bytes.len() as i32))
+        }
+    }
+}
+
+impl Default for Coverage {
+    fn default() -> Coverage {
+        Coverage::new()
+    }
+}
+
+impl Font {
+    /// Returns the byte offset and coverage level of every character in
+    /// `text` that the font does not cover exactly.
+    ///
+    /// An empty result means the font can render all of `text` itself.
+    pub fn covers(&self, text: &str) -> Vec<(usize, CoverageLevel)> {
+        let coverage = match self.get_coverage(&Language::get_default()) {
+            Some(coverage) => coverage,
+            None => return text.char_indices().map(|(i, _)| (i, CoverageLevel::None)).collect(),
+        };
+        text.char_indices()
+            .map(|(i, c)| (i, coverage.get(c as i32)))
+            .filter(|&(_, level)| level != CoverageLevel::Exact)
+            .collect()
+    }
+}
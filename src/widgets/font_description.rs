@@ -1,4 +1,4 @@
-use std::fmt::{self, Formatter, Display};
+use std::fmt::{self, Formatter, Debug, Display};
 
 use ffi;
 use glib::translate::{Stash, FromGlibPtrFull, FromGlibPtrNone, ToGlibPtr, ToGlib, from_glib_full, from_glib_none, from_glib};
@@ -20,6 +20,14 @@ impl<'a> ToGlibPtr<'a, *mut ffi::PangoFontDescription> for &'a FontDescription {
     }
 }
 
+impl<'a> ToGlibPtr<'a, *const ffi::PangoFontDescription> for &'a FontDescription {
+    type Storage = &'a FontDescription;
+
+    fn to_glib_none(&self) -> Stash<'a, *const ffi::PangoFontDescription, Self> {
+        Stash(self.0 as *const _, *self)
+    }
+}
+
 impl FromGlibPtrNone<*mut ffi::PangoFontDescription> for FontDescription {
     unsafe fn from_glib_none(ptr: *mut ffi::PangoFontDescription) -> Self {
         let tmp = ffi::pango_font_description_copy(ptr);
@@ -124,6 +132,18 @@ impl FontDescription {
     pub fn set_stretch(&self, stretch: Stretch) {
         unsafe { ffi::pango_font_description_set_stretch(self.0, stretch.to_glib()) }
     }
+
+    pub fn get_style(&self) -> Style {
+        unsafe { from_glib(ffi::pango_font_description_get_style(self.0)) }
+    }
+
+    pub fn get_variant(&self) -> Variant {
+        unsafe { from_glib(ffi::pango_font_description_get_variant(self.0)) }
+    }
+
+    pub fn get_stretch(&self) -> Stretch {
+        unsafe { from_glib(ffi::pango_font_description_get_stretch(self.0)) }
+    }
 }
 
 impl Display for FontDescription {
@@ -133,6 +153,12 @@ impl Display for FontDescription {
     }
 }
 
+impl Debug for FontDescription {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "FontDescription({:?})", self.to_string())
+    }
+}
+
 impl PartialEq for FontDescription {
     fn eq(&self, other: &FontDescription) -> bool {
         unsafe { from_glib(ffi::pango_font_description_equal(self.0, other.0)) }
@@ -0,0 +1,31 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::mem;
+use std::ptr;
+use std::slice;
+
+use ffi;
+use glib_ffi;
+use glib::translate::*;
+
+use FontFace;
+
+impl FontFace {
+    /// Returns the available sizes of a bitmap face in Pango units, in
+    /// ascending order, or `None` for scalable faces.
+    pub fn list_sizes(&self) -> Option<Vec<i32>> {
+        unsafe {
+            let mut sizes = ptr::null_mut();
+            let mut n_sizes = mem::uninitialized();
+            ffi::pango_font_face_list_sizes(self.to_glib_none().0, &mut sizes, &mut n_sizes);
+            if sizes.is_null() {
+                return None;
+            }
+            let ret = slice::from_raw_parts(sizes, n_sizes as usize).to_vec();
+            glib_ffi::g_free(sizes as glib_ffi::gpointer);
+            Some(ret)
+        }
+    }
+}
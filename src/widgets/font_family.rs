@@ -0,0 +1,36 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::vec;
+
+use Context;
+use FontFace;
+use FontFamily;
+use FontMap;
+use FontMapExt;
+
+impl Context {
+    /// Iterates over the font families available to the context's font map.
+    ///
+    /// ```ignore
+    /// let monospace: Vec<_> = context.families().filter(|f| f.is_monospace()).collect();
+    /// ```
+    pub fn families(&self) -> vec::IntoIter<FontFamily> {
+        self.list_families().into_iter()
+    }
+}
+
+impl FontMap {
+    /// Iterates over the font families known to the font map.
+    pub fn families(&self) -> vec::IntoIter<FontFamily> {
+        self.list_families().into_iter()
+    }
+}
+
+impl FontFamily {
+    /// Iterates over the faces (regular, bold, ...) of the family.
+    pub fn faces(&self) -> vec::IntoIter<FontFace> {
+        self.list_faces().into_iter()
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use ffi;
+use glib_ffi;
+use glib::translate::*;
+use libc::c_void;
+
+use Font;
+use Fontset;
+use super::callback::call_from_c;
+
+impl Fontset {
+    /// Calls `func` for each font in the fontset, in fallback order, until
+    /// it returns `true`.
+    ///
+    /// `func` is called from C code, a panic in it aborts the process.
+    pub fn foreach<F: FnMut(&Font) -> bool>(&self, mut func: F) {
+        unsafe {
+            let mut func: &mut FnMut(&Font) -> bool = &mut func;
+            let data = &mut func as *mut &mut FnMut(&Font) -> bool as *mut c_void;
+            ffi::pango_fontset_foreach(self.to_glib_none().0, Some(foreach_trampoline), data);
+        }
+    }
+
+    /// Returns all fonts of the fontset in fallback order.
+    pub fn get_fonts(&self) -> Vec<Font> {
+        let mut ret = Vec::new();
+        self.foreach(|font| {
+            ret.push(font.clone());
+            false
+        });
+        ret
+    }
+}
+
+unsafe extern "C" fn foreach_trampoline(_fontset: *mut ffi::PangoFontset, font: *mut ffi::PangoFont,
+                                        data: glib_ffi::gpointer) -> glib_ffi::gboolean {
+    let func = &mut *(data as *mut &mut FnMut(&Font) -> bool);
+    let font: Font = from_glib_none(font);
+    call_from_c(|| func(&font)).to_glib()
+}
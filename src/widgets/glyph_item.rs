@@ -0,0 +1,116 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::mem;
+
+use ffi;
+use glib::translate::*;
+
+use AttrList;
+use GlyphItem;
+use GlyphString;
+use Item;
+use LogAttr;
+use super::log_attr::log_attrs_to_bits;
+
+/// A run of a laid out line: an item together with its shaped glyphs.
+pub type LayoutRun = GlyphItem;
+
+impl GlyphItem {
+    /// Returns a copy of the item describing the text of this run.
+    pub fn item(&self) -> Item {
+        unsafe {
+            let ptr: *const ffi::PangoGlyphItem = self.to_glib_none().0;
+            from_glib_none((*ptr).item)
+        }
+    }
+
+    /// Returns a copy of the glyphs this run was shaped into.
+    pub fn glyphs(&self) -> GlyphString {
+        unsafe {
+            let ptr: *const ffi::PangoGlyphItem = self.to_glib_none().0;
+            from_glib_none((*ptr).glyphs)
+        }
+    }
+
+    /// Splits off the part of the run before the byte index `split_index`
+    /// and returns it, `self` keeps the remainder.
+    ///
+    /// `text` is the text the item's offsets refer to, usually the text of
+    /// the whole layout. `split_index` is relative to the start of the item
+    /// and must fall on a cluster boundary strictly inside it.
+    pub fn split(&mut self, text: &str, split_index: i32) -> GlyphItem {
+        assert_item_in_text(self, text);
+        let (offset, length) = (self.item_ref().offset, self.item_ref().length);
+        assert!(split_index > 0 && split_index < length, "split_index must be inside the item");
+        assert!(text.is_char_boundary((offset + split_index) as usize),
+                "split_index must be on a character boundary");
+        unsafe {
+            from_glib_full(ffi::pango_glyph_item_split(self.to_glib_none_mut().0, text.to_glib_none().0,
+                                                       split_index))
+        }
+    }
+
+    /// Splits the run wherever the attributes of `list` change and applies
+    /// them to the resulting runs.
+    ///
+    /// Only attributes that don't affect shaping, such as colors or
+    /// underlines, should be used. `text` is the text the item's offsets
+    /// refer to.
+    pub fn apply_attrs(mut self, text: &str, list: &AttrList) -> Vec<GlyphItem> {
+        assert_item_in_text(&self, text);
+        unsafe {
+            let ret = ffi::pango_glyph_item_apply_attrs(self.to_glib_none_mut().0, text.to_glib_none().0,
+                                                        list.to_glib_none().0);
+            // The glyph item is reused or freed by Pango.
+            mem::forget(self);
+            FromGlibPtrContainer::from_glib_full(ret)
+        }
+    }
+
+    /// Adds `letter_spacing` Pango units between graphemes, without adding
+    /// space at the start or end of the run.
+    ///
+    /// `log_attrs` are the attributes of the item's text as returned by
+    /// `get_log_attrs()`, starting with the position before its first
+    /// character.
+    pub fn letter_space(&mut self, text: &str, log_attrs: &[LogAttr], letter_spacing: i32) {
+        assert_item_in_text(self, text);
+        assert!(log_attrs.len() > self.item_ref().num_chars as usize,
+                "log_attrs must cover every character of the item");
+        let mut attrs = log_attrs_to_bits(log_attrs);
+        unsafe {
+            ffi::pango_glyph_item_letter_space(self.to_glib_none_mut().0, text.to_glib_none().0,
+                                               attrs.as_mut_ptr() as *mut ffi::PangoLogAttr, letter_spacing);
+        }
+    }
+
+    /// Returns the advance of every character of the run in Pango units.
+    ///
+    /// Characters of a multi-character cluster share its width equally, all
+    /// widths are positive even in right-to-left runs.
+    pub fn get_logical_widths(&self, text: &str) -> Vec<i32> {
+        assert_item_in_text(self, text);
+        let mut widths = vec![0; self.item_ref().num_chars as usize];
+        unsafe {
+            ffi::pango_glyph_item_get_logical_widths(mut_override(self.to_glib_none().0), text.to_glib_none().0,
+                                                     widths.as_mut_ptr());
+        }
+        widths
+    }
+
+    fn item_ref(&self) -> &ffi::PangoItem {
+        unsafe {
+            let ptr: *const ffi::PangoGlyphItem = self.to_glib_none().0;
+            &*(*ptr).item
+        }
+    }
+}
+
+// Pango reads the item's range of `text` without checking its length.
+pub fn assert_item_in_text(glyph_item: &GlyphItem, text: &str) {
+    let item = glyph_item.item_ref();
+    assert!(item.offset >= 0 && item.length >= 0 && (item.offset as usize + item.length as usize) <= text.len(),
+            "the item is outside of text");
+}
@@ -0,0 +1,161 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::mem;
+
+use ffi;
+use glib::translate::*;
+
+use GlyphItem;
+use super::glyph_item::assert_item_in_text;
+
+/// A cluster of a `GlyphItem`: the glyphs produced from a run of
+/// characters.
+///
+/// Glyph ranges are in visual order, so in right-to-left runs
+/// `start_glyph` is greater than `end_glyph`. The `end_*` values are
+/// exclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClusterInfo {
+    pub start_glyph: i32,
+    pub end_glyph: i32,
+    /// Byte index in the text the glyph item's offsets refer to.
+    pub start_index: i32,
+    pub end_index: i32,
+    /// Character offset from the start of the item.
+    pub start_char: i32,
+    pub end_char: i32,
+}
+
+/// Iterates over the clusters of a `GlyphItem` in logical order, or in
+/// reverse logical order when created with `GlyphItem::clusters_rev()`.
+pub struct GlyphItemIter<'a> {
+    iter: ffi::PangoGlyphItemIter,
+    // Pango keeps a pointer to the text.
+    _text: CString,
+    forward: bool,
+    // Whether `iter` points at a cluster that was not yielded yet.
+    pending: bool,
+    glyph_item: PhantomData<&'a GlyphItem>,
+}
+
+impl<'a> GlyphItemIter<'a> {
+    fn new(glyph_item: &'a GlyphItem, text: &str, forward: bool) -> GlyphItemIter<'a> {
+        assert_item_in_text(glyph_item, text);
+        let text = CString::new(text).unwrap();
+        unsafe {
+            let mut iter = mem::zeroed();
+            let glyph_item = mut_override(glyph_item.to_glib_none().0);
+            let pending = if forward {
+                ffi::pango_glyph_item_iter_init_start(&mut iter, glyph_item, text.as_ptr())
+            } else {
+                ffi::pango_glyph_item_iter_init_end(&mut iter, glyph_item, text.as_ptr())
+            };
+            GlyphItemIter {
+                iter: iter,
+                _text: text,
+                forward: forward,
+                pending: from_glib(pending),
+                glyph_item: PhantomData,
+            }
+        }
+    }
+
+    fn cluster(&self) -> ClusterInfo {
+        ClusterInfo {
+            start_glyph: self.iter.start_glyph,
+            end_glyph: self.iter.end_glyph,
+            start_index: self.iter.start_index,
+            end_index: self.iter.end_index,
+            start_char: self.iter.start_char,
+            end_char: self.iter.end_char,
+        }
+    }
+}
+
+impl<'a> Iterator for GlyphItemIter<'a> {
+    type Item = ClusterInfo;
+
+    fn next(&mut self) -> Option<ClusterInfo> {
+        if !self.pending {
+            return None;
+        }
+        let ret = self.cluster();
+        self.pending = unsafe {
+            if self.forward {
+                from_glib(ffi::pango_glyph_item_iter_next_cluster(&mut self.iter))
+            } else {
+                from_glib(ffi::pango_glyph_item_iter_prev_cluster(&mut self.iter))
+            }
+        };
+        Some(ret)
+    }
+}
+
+impl GlyphItem {
+    /// Iterates over the clusters of the run in logical order.
+    ///
+    /// `text` is the text the item's offsets refer to, usually the text of
+    /// the whole layout.
+    pub fn clusters<'a>(&'a self, text: &str) -> GlyphItemIter<'a> {
+        GlyphItemIter::new(self, text, true)
+    }
+
+    /// Iterates over the clusters of the run from the logical end to the
+    /// start.
+    pub fn clusters_rev<'a>(&'a self, text: &str) -> GlyphItemIter<'a> {
+        GlyphItemIter::new(self, text, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use widgets::test_util;
+    use super::*;
+
+    fn cluster(start_glyph: i32, end_glyph: i32, start_index: i32, end_index: i32, start_char: i32,
+               end_char: i32) -> ClusterInfo {
+        ClusterInfo {
+            start_glyph: start_glyph,
+            end_glyph: end_glyph,
+            start_index: start_index,
+            end_index: end_index,
+            start_char: start_char,
+            end_char: end_char,
+        }
+    }
+
+    #[test]
+    fn mixed_direction_clusters() {
+        let text = "abc \u{5d0}\u{5d1}\u{5d2}";
+        let layout = test_util::layout(text);
+        let runs = layout.get_line(0).unwrap().runs();
+        assert_eq!(runs.len(), 2);
+
+        let ltr: Vec<_> = runs[0].clusters(text).collect();
+        assert_eq!(ltr, [cluster(0, 1, 0, 1, 0, 1), cluster(1, 2, 1, 2, 1, 2), cluster(2, 3, 2, 3, 2, 3),
+                         cluster(3, 4, 3, 4, 3, 4)]);
+        let mut ltr_rev: Vec<_> = runs[0].clusters_rev(text).collect();
+        ltr_rev.reverse();
+        assert_eq!(ltr_rev, ltr);
+
+        // The glyphs of the right-to-left run are stored in visual order.
+        let rtl: Vec<_> = runs[1].clusters(text).collect();
+        assert_eq!(rtl, [cluster(2, 1, 4, 6, 0, 1), cluster(1, 0, 6, 8, 1, 2), cluster(0, -1, 8, 10, 2, 3)]);
+        assert!(rtl.iter().all(|cluster| cluster.start_glyph > cluster.end_glyph));
+        let mut rtl_rev: Vec<_> = runs[1].clusters_rev(text).collect();
+        rtl_rev.reverse();
+        assert_eq!(rtl_rev, rtl);
+    }
+
+    #[test]
+    #[should_panic]
+    fn text_shorter_than_item() {
+        let layout = test_util::layout("abc");
+        let runs = layout.get_line(0).unwrap().runs();
+        runs[0].clusters("ab");
+    }
+}
@@ -2,9 +2,56 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
+use std::mem;
+use std::slice;
+
 use ffi;
+use glib::translate::*;
 use libc::c_int;
 
+use Analysis;
+use Font;
+use Rectangle;
+
+/// Positioning of a glyph, all values in Pango units.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct GlyphGeometry {
+    /// Logical width (advance) of the glyph.
+    pub width: i32,
+    pub x_offset: i32,
+    pub y_offset: i32,
+}
+
+/// A single glyph of a `GlyphString`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct GlyphInfo {
+    /// The glyph index in the font, see `GLYPH_EMPTY` and
+    /// `GLYPH_UNKNOWN_FLAG` for special values.
+    pub glyph: u32,
+    pub geometry: GlyphGeometry,
+    // `PangoGlyphVisAttr`, a single one-bit bitfield.
+    attr: u32,
+}
+
+impl GlyphInfo {
+    /// Whether this glyph is the first of a cluster.
+    pub fn is_cluster_start(&self) -> bool {
+        if cfg!(target_endian = "little") {
+            self.attr & 1 != 0
+        } else {
+            self.attr & (1 << 31) != 0
+        }
+    }
+}
+
+/// Glyph id of a zero-width glyph.
+pub const GLYPH_EMPTY: u32 = 0x0FFFFFFF;
+/// Set on glyph ids of characters the font has no glyph for, the rest of
+/// the value is the Unicode code point.
+pub const GLYPH_UNKNOWN_FLAG: u32 = 0x10000000;
+
 pub struct GlyphString {
     pointer: *mut ffi::PangoGlyphString
 }
@@ -37,6 +84,122 @@ impl GlyphString {
     pub fn set_size(&self, new_len: i32) {
         unsafe { ffi::pango_glyph_string_set_size(self.pointer, new_len as c_int) }
     }
+
+    pub fn num_glyphs(&self) -> usize {
+        unsafe { (*self.pointer).num_glyphs as usize }
+    }
+
+    pub fn glyphs(&self) -> &[GlyphInfo] {
+        unsafe {
+            if self.num_glyphs() == 0 {
+                return &[];
+            }
+            slice::from_raw_parts((*self.pointer).glyphs as *const GlyphInfo, self.num_glyphs())
+        }
+    }
+
+    /// For each glyph, the byte offset of the start of its cluster in the
+    /// text of the item it was shaped from.
+    pub fn log_clusters(&self) -> &[i32] {
+        unsafe {
+            if self.num_glyphs() == 0 {
+                return &[];
+            }
+            slice::from_raw_parts((*self.pointer).log_clusters as *const i32, self.num_glyphs())
+        }
+    }
+
+    /// Sum of the logical widths of all glyphs, in Pango units.
+    pub fn get_width(&self) -> i32 {
+        unsafe { ffi::pango_glyph_string_get_width(self.pointer) }
+    }
+
+    /// Returns the ink and logical extents of the glyphs rendered with
+    /// `font`.
+    pub fn extents(&self, font: &Font) -> (Rectangle, Rectangle) {
+        unsafe {
+            let mut ink_rect = Rectangle::uninitialized();
+            let mut logical_rect = Rectangle::uninitialized();
+            ffi::pango_glyph_string_extents(self.pointer, font.to_glib_none().0,
+                                            ink_rect.to_glib_none_mut().0, logical_rect.to_glib_none_mut().0);
+            (ink_rect, logical_rect)
+        }
+    }
+
+    /// Same as `extents()` for the glyphs `start..end` only. The x origin
+    /// of the result is the left edge of glyph `start`.
+    pub fn extents_range(&self, start: i32, end: i32, font: &Font) -> (Rectangle, Rectangle) {
+        unsafe {
+            let mut ink_rect = Rectangle::uninitialized();
+            let mut logical_rect = Rectangle::uninitialized();
+            ffi::pango_glyph_string_extents_range(self.pointer, start, end, font.to_glib_none().0,
+                                                  ink_rect.to_glib_none_mut().0, logical_rect.to_glib_none_mut().0);
+            (ink_rect, logical_rect)
+        }
+    }
+
+    /// Converts the byte offset `index_` of `text` into an x position.
+    /// `text` and `analysis` must be the ones the glyphs were shaped from.
+    pub fn index_to_x(&self, text: &str, analysis: &Analysis, index_: i32, trailing: bool) -> i32 {
+        unsafe {
+            let mut x_pos = mem::uninitialized();
+            ffi::pango_glyph_string_index_to_x(self.pointer, text.to_glib_none().0 as *mut _, text.len() as c_int,
+                                               analysis.to_glib_ptr(), index_, trailing.to_glib(), &mut x_pos);
+            x_pos
+        }
+    }
+
+    /// Converts an x position into a byte offset of `text` and whether the
+    /// position is in the trailing half of that character.
+    pub fn x_to_index(&self, text: &str, analysis: &Analysis, x_pos: i32) -> (i32, bool) {
+        unsafe {
+            let mut index_ = mem::uninitialized();
+            let mut trailing = mem::uninitialized();
+            ffi::pango_glyph_string_x_to_index(self.pointer, text.to_glib_none().0 as *mut _, text.len() as c_int,
+                                               analysis.to_glib_ptr(), x_pos, &mut index_, &mut trailing);
+            (index_, trailing != 0)
+        }
+    }
+
+    /// Returns the logical width of each character of `text`, splitting
+    /// the width of multi-character clusters evenly.
+    pub fn get_logical_widths(&self, text: &str, embedding_level: i32) -> Vec<i32> {
+        let mut logical_widths = vec![0; text.chars().count()];
+        unsafe {
+            ffi::pango_glyph_string_get_logical_widths(self.pointer, text.to_glib_none().0, text.len() as c_int,
+                                                       embedding_level, logical_widths.as_mut_ptr());
+        }
+        logical_widths
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtr<'a, *const ffi::PangoGlyphString> for GlyphString {
+    type Storage = &'a Self;
+
+    fn to_glib_none(&'a self) -> Stash<'a, *const ffi::PangoGlyphString, Self> {
+        Stash(self.pointer as *const _, self)
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtrMut<'a, *mut ffi::PangoGlyphString> for GlyphString {
+    type Storage = &'a mut Self;
+
+    fn to_glib_none_mut(&'a mut self) -> StashMut<'a, *mut ffi::PangoGlyphString, Self> {
+        StashMut(self.pointer, self)
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrNone<*mut ffi::PangoGlyphString> for GlyphString {
+    unsafe fn from_glib_none(ptr: *mut ffi::PangoGlyphString) -> Self {
+        let tmp = ffi::pango_glyph_string_copy(ptr);
+        assert!(!tmp.is_null());
+        GlyphString {
+            pointer: tmp
+        }
+    }
 }
 
 impl Drop for GlyphString {
@@ -47,4 +210,25 @@ impl Drop for GlyphString {
         unsafe { ffi::pango_glyph_string_free(self.pointer); }
         self.pointer = ::std::ptr::null_mut();
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use widgets::test_util;
+
+    #[test]
+    fn cluster_starts_follow_log_clusters() {
+        // There is no precomposed q with acute, the accent is a glyph of
+        // its own in the cluster of the q.
+        let layout = test_util::layout("q\u{301}b");
+        let runs = layout.get_line(0).unwrap().runs();
+        let glyphs = runs[0].glyphs();
+        let clusters = glyphs.log_clusters();
+        assert_eq!(glyphs.glyphs().len(), 3);
+        for (i, info) in glyphs.glyphs().iter().enumerate() {
+            assert_eq!(info.is_cluster_start(), i == 0 || clusters[i] != clusters[i - 1], "glyph {}", i);
+        }
+        let starts: Vec<bool> = glyphs.glyphs().iter().map(|info| info.is_cluster_start()).collect();
+        assert_eq!(starts, [true, false, true]);
+    }
+}
@@ -3,55 +3,78 @@
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
 use ffi;
-use libc::c_int;
+use glib::translate::*;
 
-pub struct Item {
-    pointer: *mut ffi::PangoItem
+use Analysis;
+
+// A segment of text with uniform script, font, direction and attributes,
+// as produced by itemization.
+glib_wrapper! {
+    pub struct Item(Boxed<ffi::PangoItem>);
+
+    match fn {
+        copy => |ptr| ffi::pango_item_copy(mut_override(ptr)),
+        free => |ptr| ffi::pango_item_free(ptr),
+    }
 }
 
 impl Item {
-    pub fn new() -> Option<Item> {
-        let tmp = unsafe { ffi::pango_item_new() };
+    pub fn new() -> Item {
+        unsafe { from_glib_full(ffi::pango_item_new()) }
+    }
+
+    /// Byte offset of the item in the text it was created from.
+    pub fn offset(&self) -> i32 {
+        unsafe { (*self.as_ptr()).offset }
+    }
 
-        if tmp.is_null() {
-            None
-        } else {
-            Some(Item {
-                pointer: tmp
-            })
-        }
+    pub fn set_offset(&mut self, offset: i32) {
+        unsafe { (*self.as_mut_ptr()).offset = offset }
     }
 
-    pub fn copy(&self) -> Option<Item> {
-        let tmp = unsafe { ffi::pango_item_copy(self.pointer) };
+    /// Length of the item in bytes.
+    pub fn length(&self) -> i32 {
+        unsafe { (*self.as_ptr()).length }
+    }
 
-        if tmp.is_null() {
-            None
-        } else {
-            Some(Item {
-                pointer: tmp
-            })
-        }
+    pub fn set_length(&mut self, length: i32) {
+        unsafe { (*self.as_mut_ptr()).length = length }
     }
 
-    pub fn split(&self, split_index: i32, split_offset: i32) -> Option<Item> {
-        let tmp = unsafe { ffi::pango_item_split(self.pointer, split_index as c_int, split_offset as c_int) };
+    /// Length of the item in characters.
+    pub fn num_chars(&self) -> i32 {
+        unsafe { (*self.as_ptr()).num_chars }
+    }
+
+    pub fn set_num_chars(&mut self, num_chars: i32) {
+        unsafe { (*self.as_mut_ptr()).num_chars = num_chars }
+    }
 
-        if tmp.is_null() {
-            None
-        } else {
-            Some(Item {
-                pointer: tmp
-            })
-        }
+    pub fn analysis(&self) -> &Analysis {
+        unsafe { Analysis::from_glib_ptr(&(*self.as_ptr()).analysis) }
+    }
+
+    pub fn analysis_mut(&mut self) -> &mut Analysis {
+        unsafe { Analysis::from_glib_ptr_mut(&mut (*self.as_mut_ptr()).analysis) }
+    }
+
+    /// Splits off the first `split_index` bytes (`split_offset` characters)
+    /// and returns them as a new item, `self` keeps the remainder.
+    pub fn split(&mut self, split_index: i32, split_offset: i32) -> Item {
+        unsafe { from_glib_full(ffi::pango_item_split(self.as_mut_ptr(), split_index, split_offset)) }
+    }
+
+    fn as_ptr(&self) -> *const ffi::PangoItem {
+        self.to_glib_none().0
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut ffi::PangoItem {
+        self.to_glib_none_mut().0
     }
 }
 
-impl Drop for Item {
-    fn drop(&mut self) {
-        if !self.pointer.is_null() {
-            unsafe { ffi::pango_item_free(self.pointer) };
-            self.pointer = ::std::ptr::null_mut();
-        }
+impl Default for Item {
+    fn default() -> Item {
+        Item::new()
     }
-}
\ No newline at end of file
+}
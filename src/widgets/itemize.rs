@@ -0,0 +1,116 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::ptr;
+
+use ffi;
+use glib_ffi;
+use glib::translate::*;
+
+use {
+    AttrIterator,
+    AttrList,
+    Context,
+    Direction,
+    Item,
+};
+
+/// Breaks the `length` bytes of `text` starting at `start_index` into
+/// items with uniform script, font, direction and attributes, in logical
+/// order. Item offsets are relative to the start of `text`.
+///
+/// `cached_iter` may be an iterator over `attrs`, not over a copy of it,
+/// that is reused between calls on consecutive ranges of the same text.
+/// Pango moves it to the range containing the end of the itemized text,
+/// iterating it afterwards continues from there.
+pub fn itemize(context: &Context, text: &str, start_index: i32, length: i32, attrs: &AttrList,
+               mut cached_iter: Option<&mut AttrIterator>) -> Vec<Item> {
+    check_itemize_args(text, start_index, length, attrs, &cached_iter);
+    unsafe {
+        let cached_iter_ptr = match cached_iter {
+            Some(ref mut iter) => iter.to_glib_none_mut().0,
+            None => ptr::null_mut(),
+        };
+        let items = ffi::pango_itemize(context.to_glib_none().0, text.to_glib_none().0, start_index, length,
+                                       attrs.to_glib_none().0, cached_iter_ptr);
+        if let Some(iter) = cached_iter {
+            iter.reset_finished();
+        }
+        FromGlibPtrContainer::from_glib_full(items)
+    }
+}
+
+/// Like `itemize()`, but uses `base_dir` instead of the context's base
+/// direction for text with neutral direction.
+pub fn itemize_with_base_dir(context: &Context, base_dir: Direction, text: &str, start_index: i32, length: i32,
+                             attrs: &AttrList, mut cached_iter: Option<&mut AttrIterator>) -> Vec<Item> {
+    check_itemize_args(text, start_index, length, attrs, &cached_iter);
+    unsafe {
+        let cached_iter_ptr = match cached_iter {
+            Some(ref mut iter) => iter.to_glib_none_mut().0,
+            None => ptr::null_mut(),
+        };
+        let items = ffi::pango_itemize_with_base_dir(context.to_glib_none().0, base_dir.to_glib(),
+                                                     text.to_glib_none().0, start_index, length,
+                                                     attrs.to_glib_none().0, cached_iter_ptr);
+        if let Some(iter) = cached_iter {
+            iter.reset_finished();
+        }
+        FromGlibPtrContainer::from_glib_full(items)
+    }
+}
+
+fn check_itemize_args(text: &str, start_index: i32, length: i32, attrs: &AttrList,
+                      cached_iter: &Option<&mut AttrIterator>) {
+    let end_index = start_index.checked_add(length).expect("start_index + length overflows");
+    assert!(start_index >= 0 && length >= 0 && end_index as usize <= text.len(),
+            "the range must be inside text");
+    assert!(text.is_char_boundary(start_index as usize) && text.is_char_boundary(end_index as usize),
+            "the range must start and end on character boundaries");
+    // Pango ignores `attrs` in favour of the iterator's list.
+    if let Some(ref iter) = *cached_iter {
+        let iter_list: *mut ffi::PangoAttrList = iter.get_attr_list().to_glib_none().0;
+        let list: *mut ffi::PangoAttrList = attrs.to_glib_none().0;
+        assert!(iter_list == list, "cached_iter must iterate over attrs");
+    }
+}
+
+/// Returns the order in which `items`, given in logical order, are
+/// displayed: the first element is the index of the leftmost item.
+///
+/// Useful to reorder data kept in parallel to the items, see
+/// `reorder_items()` to reorder the items themselves.
+pub fn visual_order(items: &[Item]) -> Vec<usize> {
+    unsafe {
+        let mut logical_items = ptr::null_mut();
+        for item in items.iter().rev() {
+            let ptr: *const ffi::PangoItem = item.to_glib_none().0;
+            logical_items = glib_ffi::g_list_prepend(logical_items, ptr as glib_ffi::gpointer);
+        }
+
+        // The result holds the same item pointers, only the list is new.
+        let visual_items = ffi::pango_reorder_items(logical_items);
+        let mut ret = Vec::with_capacity(items.len());
+        let mut l = visual_items;
+        while !l.is_null() {
+            let index = items.iter().position(|item| {
+                let ptr: *const ffi::PangoItem = item.to_glib_none().0;
+                ptr as glib_ffi::gpointer == (*l).data
+            });
+            ret.push(index.expect("pango_reorder_items() returned an unknown item"));
+            l = (*l).next;
+        }
+        glib_ffi::g_list_free(visual_items);
+        glib_ffi::g_list_free(logical_items);
+        ret
+    }
+}
+
+/// Reorders `items` from logical to visual (left to right) order, based on
+/// their bidi embedding levels.
+pub fn reorder_items(items: Vec<Item>) -> Vec<Item> {
+    let order = visual_order(&items);
+    let mut items: Vec<Option<Item>> = items.into_iter().map(Some).collect();
+    order.into_iter().map(|i| items[i].take().unwrap()).collect()
+}
@@ -0,0 +1,132 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::fmt;
+use std::mem;
+use std::str::FromStr;
+
+use ffi;
+use glib::translate::*;
+
+use Script;
+
+/// An RFC-3066 language tag such as `"en-us"`.
+///
+/// Languages are interned by Pango, so two `Language` values with the same
+/// tag compare equal.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Language(*mut ffi::PangoLanguage);
+
+impl Language {
+    /// Canonicalizes `language` (lowercase, `_` replaced by `-`) and
+    /// returns the matching `Language`.
+    pub fn from_string(language: &str) -> Language {
+        unsafe { from_glib_none(ffi::pango_language_from_string(language.to_glib_none().0)) }
+    }
+
+    /// Returns the language of the current locale.
+    pub fn get_default() -> Language {
+        unsafe { from_glib_none(ffi::pango_language_get_default()) }
+    }
+
+    /// Checks the language against a list of ranges separated by `;`, `:`,
+    /// `,` or space. `*` matches every language.
+    pub fn matches(&self, range_list: &str) -> bool {
+        unsafe { from_glib(ffi::pango_language_matches(self.0, range_list.to_glib_none().0)) }
+    }
+
+    /// Whether text in `script` is usually written in this language.
+    pub fn includes_script(&self, script: Script) -> bool {
+        unsafe { from_glib(ffi::pango_language_includes_script(self.0, script.to_glib())) }
+    }
+
+    /// Returns the scripts used to write this language, empty if unknown.
+    pub fn get_scripts(&self) -> Vec<Script> {
+        unsafe {
+            let mut num_scripts = mem::uninitialized();
+            let scripts = ffi::pango_language_get_scripts(self.0, &mut num_scripts);
+            if scripts.is_null() {
+                return Vec::new();
+            }
+            (0..num_scripts as isize).map(|i| from_glib(*scripts.offset(i))).collect()
+        }
+    }
+
+    /// Returns a short text that is representative of the language, useful
+    /// for font previews.
+    pub fn get_sample_string(&self) -> String {
+        unsafe { from_glib_none(ffi::pango_language_get_sample_string(self.0)) }
+    }
+}
+
+impl Default for Language {
+    fn default() -> Language {
+        Language::get_default()
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s: String = unsafe { from_glib_none(ffi::pango_language_to_string(self.0)) };
+        write!(f, "{}", s)
+    }
+}
+
+impl fmt::Debug for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Language({:?})", self.to_string())
+    }
+}
+
+impl FromStr for Language {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Language, ()> {
+        Ok(Language::from_string(s))
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtr<'a, *mut ffi::PangoLanguage> for &'a Language {
+    type Storage = &'a Language;
+
+    fn to_glib_none(&self) -> Stash<'a, *mut ffi::PangoLanguage, Self> {
+        Stash(self.0, *self)
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtr<'a, *const ffi::PangoLanguage> for &'a Language {
+    type Storage = &'a Language;
+
+    fn to_glib_none(&self) -> Stash<'a, *const ffi::PangoLanguage, Self> {
+        Stash(self.0 as *const _, *self)
+    }
+}
+
+// `PangoLanguage` pointers are interned by Pango and live for the whole
+// process, so taking ownership or borrowing is the same thing.
+#[doc(hidden)]
+impl FromGlibPtrNone<*mut ffi::PangoLanguage> for Language {
+    unsafe fn from_glib_none(ptr: *mut ffi::PangoLanguage) -> Self {
+        assert!(!ptr.is_null());
+        Language(ptr)
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrFull<*mut ffi::PangoLanguage> for Language {
+    unsafe fn from_glib_full(ptr: *mut ffi::PangoLanguage) -> Self {
+        assert!(!ptr.is_null());
+        Language(ptr)
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrNone<*const ffi::PangoLanguage> for Language {
+    unsafe fn from_glib_none(ptr: *const ffi::PangoLanguage) -> Self {
+        assert!(!ptr.is_null());
+        Language(ptr as *mut _)
+    }
+}
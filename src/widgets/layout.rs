@@ -0,0 +1,89 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::mem;
+use std::ptr;
+
+use ffi;
+use glib_ffi;
+use glib::translate::*;
+
+use Layout;
+use LogAttr;
+use MarkupError;
+use super::log_attr::log_attrs_from_glib_none_num;
+use super::markup::parse_markup_checked;
+
+impl Layout {
+    /// Like `set_markup()`, but reports malformed markup instead of only
+    /// logging a warning. The layout is left untouched on failure.
+    pub fn try_set_markup(&self, markup: &str) -> Result<(), MarkupError> {
+        let (attrs, text, _) = try!(parse_markup_checked(markup, None));
+        self.set_text(&text, text.len() as i32);
+        self.set_attributes(&attrs);
+        Ok(())
+    }
+
+    /// Like `set_markup_with_accel()`, but reports malformed markup instead
+    /// of only logging a warning. The layout is left untouched on failure.
+    ///
+    /// Returns the accelerator character, if any.
+    pub fn try_set_markup_with_accel(&self, markup: &str, accel_marker: char)
+            -> Result<Option<char>, MarkupError> {
+        let (attrs, text, accel_char) = try!(parse_markup_checked(markup, Some(accel_marker)));
+        self.set_text(&text, text.len() as i32);
+        self.set_attributes(&attrs);
+        Ok(accel_char)
+    }
+
+    /// Returns the boundary information for every character of the text,
+    /// plus one entry for the end of the text.
+    pub fn get_log_attrs(&self) -> Vec<LogAttr> {
+        unsafe {
+            let mut attrs = ptr::null_mut();
+            let mut n_attrs = mem::uninitialized();
+            ffi::pango_layout_get_log_attrs(self.to_glib_none().0, &mut attrs, &mut n_attrs);
+            let ret = log_attrs_from_glib_none_num(attrs, n_attrs as usize);
+            glib_ffi::g_free(attrs as glib_ffi::gpointer);
+            ret
+        }
+    }
+
+    /// Same as `get_log_attrs()` but reads the layout's cached array
+    /// instead of having Pango allocate a copy.
+    pub fn get_log_attrs_readonly(&self) -> Vec<LogAttr> {
+        unsafe {
+            let mut n_attrs = mem::uninitialized();
+            let attrs = ffi::pango_layout_get_log_attrs_readonly(self.to_glib_none().0, &mut n_attrs);
+            log_attrs_from_glib_none_num(attrs, n_attrs as usize)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use widgets::test_util;
+    use LayoutSnapshot;
+
+    #[test]
+    fn try_set_markup_reports_position() {
+        let layout = test_util::layout("<b>old</b>");
+        let before = LayoutSnapshot::new(&layout);
+
+        let err = layout.try_set_markup("<foo>x</foo>").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), Some(6)));
+        assert_eq!(err.message, "Unknown tag 'foo'");
+
+        let after = LayoutSnapshot::new(&layout);
+        assert_eq!(after.text, "old");
+        assert_eq!(after.attributes, before.attributes);
+    }
+
+    #[test]
+    fn try_set_markup_second_line() {
+        let layout = test_util::layout("");
+        let err = layout.try_set_markup("x\n<foo>x</foo>").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(6)));
+    }
+}
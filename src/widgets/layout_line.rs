@@ -0,0 +1,35 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use ffi;
+use glib::translate::*;
+
+use LayoutLine;
+use LayoutRun;
+
+impl LayoutLine {
+    /// Byte offset of the start of the line in the layout's text.
+    pub fn get_start_index(&self) -> i32 {
+        unsafe {
+            let ptr: *mut ffi::PangoLayoutLine = self.to_glib_none().0;
+            (*ptr).start_index
+        }
+    }
+
+    /// Length of the line in bytes.
+    pub fn get_length(&self) -> i32 {
+        unsafe {
+            let ptr: *mut ffi::PangoLayoutLine = self.to_glib_none().0;
+            (*ptr).length
+        }
+    }
+
+    /// Returns the runs of the line in visual order.
+    pub fn runs(&self) -> Vec<LayoutRun> {
+        unsafe {
+            let ptr: *mut ffi::PangoLayoutLine = self.to_glib_none().0;
+            FromGlibPtrContainer::from_glib_none((*ptr).runs)
+        }
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use AttrKind;
+use FontExt;
+use Layout;
+use LayoutRun;
+use Rectangle;
+
+/// The settings and the result of laying out a `Layout`, meant to be
+/// compared between runs in tests.
+///
+/// Enumeration values and attributes are stored as their `Debug`
+/// representation, languages as their tag. With the `serde` feature all
+/// snapshot types implement `Serialize`.
+///
+/// ```ignore
+/// let snapshot = LayoutSnapshot::new(&layout);
+/// assert_eq!(serde_json::to_string_pretty(&snapshot).unwrap(), expected);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LayoutSnapshot {
+    pub text: String,
+    pub attributes: Vec<AttrSnapshot>,
+    /// Width in Pango units, -1 if unset.
+    pub width: i32,
+    /// Height in Pango units, negative values are a number of lines.
+    pub height: i32,
+    pub wrap: String,
+    pub ellipsize: String,
+    pub alignment: String,
+    pub is_wrapped: bool,
+    pub is_ellipsized: bool,
+    pub ink_rect: Rectangle,
+    pub logical_rect: Rectangle,
+    pub lines: Vec<LineSnapshot>,
+}
+
+/// An attribute of the layout with the byte range it applies to.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct AttrSnapshot {
+    pub start_index: u32,
+    pub end_index: u32,
+    /// The `AttrKind`, or `None` for attribute types it doesn't cover.
+    /// Languages are written as `Language("en-us")`.
+    pub kind: Option<String>,
+}
+
+/// A line of the layout, extents are in layout coordinates.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LineSnapshot {
+    pub start_index: i32,
+    pub length: i32,
+    pub ink_rect: Rectangle,
+    pub logical_rect: Rectangle,
+    pub baseline: i32,
+    /// The runs of the line in visual order.
+    pub runs: Vec<RunSnapshot>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RunSnapshot {
+    pub start_index: i32,
+    pub length: i32,
+    /// Bidi embedding level, odd for right-to-left runs.
+    pub level: u8,
+    /// Description of the font the run was shaped with.
+    pub font: Option<String>,
+    pub glyphs: Vec<GlyphSnapshot>,
+}
+
+/// A glyph of a run, all values in Pango units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct GlyphSnapshot {
+    pub glyph: u32,
+    pub advance: i32,
+    pub x_offset: i32,
+    pub y_offset: i32,
+}
+
+impl LayoutSnapshot {
+    pub fn new(layout: &Layout) -> LayoutSnapshot {
+        let mut attributes = Vec::new();
+        if let Some(list) = layout.get_attributes() {
+            // Nothing is removed as the closure never matches.
+            list.filter(|attr| {
+                attributes.push(AttrSnapshot {
+                    start_index: attr.get_start_index(),
+                    end_index: attr.get_end_index(),
+                    kind: attr.kind().map(|kind| describe_attr(&kind)),
+                });
+                false
+            });
+        }
+
+        let mut lines = Vec::new();
+        if let Some(mut iter) = layout.get_iter() {
+            loop {
+                if let Some(line) = iter.get_line_readonly() {
+                    let (ink_rect, logical_rect) = iter.get_line_extents();
+                    lines.push(LineSnapshot {
+                        start_index: line.get_start_index(),
+                        length: line.get_length(),
+                        ink_rect: ink_rect,
+                        logical_rect: logical_rect,
+                        baseline: iter.get_baseline(),
+                        runs: line.runs().iter().map(RunSnapshot::new).collect(),
+                    });
+                }
+                if !iter.next_line() {
+                    break;
+                }
+            }
+        }
+
+        let (ink_rect, logical_rect) = layout.get_extents();
+        LayoutSnapshot {
+            text: layout.get_text().unwrap_or_default(),
+            attributes: attributes,
+            width: layout.get_width(),
+            height: layout.get_height(),
+            wrap: format!("{:?}", layout.get_wrap()),
+            ellipsize: format!("{:?}", layout.get_ellipsize()),
+            alignment: format!("{:?}", layout.get_alignment()),
+            is_wrapped: layout.is_wrapped(),
+            is_ellipsized: layout.is_ellipsized(),
+            ink_rect: ink_rect,
+            logical_rect: logical_rect,
+            lines: lines,
+        }
+    }
+}
+
+impl RunSnapshot {
+    fn new(run: &LayoutRun) -> RunSnapshot {
+        let item = run.item();
+        let analysis = item.analysis();
+        let glyphs = run.glyphs();
+        RunSnapshot {
+            start_index: item.offset(),
+            length: item.length(),
+            level: analysis.level(),
+            font: analysis.font().and_then(|font| font.describe()).map(|desc| desc.to_string()),
+            glyphs: glyphs.glyphs().iter().map(|info| GlyphSnapshot {
+                glyph: info.glyph,
+                advance: info.geometry.width,
+                x_offset: info.geometry.x_offset,
+                y_offset: info.geometry.y_offset,
+            }).collect(),
+        }
+    }
+}
+
+fn describe_attr(kind: &AttrKind) -> String {
+    match *kind {
+        // Keep the snapshot independent of how `Language` is formatted.
+        AttrKind::Language(ref language) => format!("Language({:?})", language.to_string()),
+        ref kind => format!("{:?}", kind),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use widgets::test_util;
+    use super::*;
+
+    const MARKUP: &'static str = "Hello <span lang=\"de-ch\" weight=\"550\">Welt</span>";
+
+    // (start_index, length, number of glyphs) of each run of each line.
+    fn runs(snapshot: &LayoutSnapshot) -> Vec<Vec<(i32, i32, usize)>> {
+        snapshot.lines.iter()
+            .map(|line| line.runs.iter().map(|run| (run.start_index, run.length, run.glyphs.len())).collect())
+            .collect()
+    }
+
+    #[test]
+    fn unwrapped_layout() {
+        let snapshot = LayoutSnapshot::new(&test_util::layout(MARKUP));
+        assert_eq!(snapshot.text, "Hello Welt");
+        assert_eq!(snapshot.width, -1);
+        assert!(!snapshot.is_wrapped);
+        assert!(!snapshot.is_ellipsized);
+        assert_eq!(snapshot.lines.len(), 1);
+        assert_eq!((snapshot.lines[0].start_index, snapshot.lines[0].length), (0, 10));
+        assert_eq!(runs(&snapshot), [vec![(0, 6, 6), (6, 4, 4)]]);
+    }
+
+    #[test]
+    fn wrapped_layout() {
+        let layout = test_util::layout(MARKUP);
+        layout.set_width(1);
+        let snapshot = LayoutSnapshot::new(&layout);
+        assert!(snapshot.is_wrapped);
+        assert_eq!(snapshot.wrap, "Word");
+        assert_eq!(runs(&snapshot), [vec![(0, 6, 6)], vec![(6, 4, 4)]]);
+    }
+
+    #[test]
+    fn language_attributes() {
+        let snapshot = LayoutSnapshot::new(&test_util::layout(MARKUP));
+        assert_eq!(snapshot.attributes.len(), 2);
+        assert!(snapshot.attributes.contains(&AttrSnapshot {
+            start_index: 6,
+            end_index: 10,
+            kind: Some("Language(\"de-ch\")".to_owned()),
+        }));
+        assert!(snapshot.attributes.contains(&AttrSnapshot {
+            start_index: 6,
+            end_index: 10,
+            kind: Some("WeightValue(550)".to_owned()),
+        }));
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use ffi;
+use glib::translate::*;
+
+/// Text boundary information for a single character position.
+///
+/// There is one `LogAttr` per character plus one for the position after
+/// the last character.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LogAttr {
+    /// A line break is allowed before this character.
+    pub is_line_break: bool,
+    /// A line break is required before this character.
+    pub is_mandatory_break: bool,
+    /// Text may be broken into characters before this position.
+    pub is_char_break: bool,
+    pub is_white: bool,
+    /// The cursor can be placed before this character.
+    pub is_cursor_position: bool,
+    pub is_word_start: bool,
+    pub is_word_end: bool,
+    pub is_sentence_boundary: bool,
+    pub is_sentence_start: bool,
+    pub is_sentence_end: bool,
+    /// Backspace deletes a single character rather than the whole
+    /// grapheme cluster ending here.
+    pub backspace_deletes_character: bool,
+    /// Whitespace that can be stretched when justifying.
+    pub is_expandable_space: bool,
+    /// Word boundary as defined by UAX #29, always `false` before Pango 1.40.
+    pub is_word_boundary: bool,
+}
+
+// `PangoLogAttr` is a single `guint` of one-bit bitfields, in declaration
+// order starting from the least significant bit on little endian targets.
+#[cfg(target_endian = "little")]
+fn bit(bits: u32, n: u32) -> bool {
+    bits & (1 << n) != 0
+}
+
+#[cfg(target_endian = "big")]
+fn bit(bits: u32, n: u32) -> bool {
+    bits & (1 << (31 - n)) != 0
+}
+
+#[cfg(target_endian = "little")]
+fn set_bit(value: bool, n: u32) -> u32 {
+    (value as u32) << n
+}
+
+#[cfg(target_endian = "big")]
+fn set_bit(value: bool, n: u32) -> u32 {
+    (value as u32) << (31 - n)
+}
+
+impl LogAttr {
+    fn from_bits(bits: u32) -> LogAttr {
+        LogAttr {
+            is_line_break: bit(bits, 0),
+            is_mandatory_break: bit(bits, 1),
+            is_char_break: bit(bits, 2),
+            is_white: bit(bits, 3),
+            is_cursor_position: bit(bits, 4),
+            is_word_start: bit(bits, 5),
+            is_word_end: bit(bits, 6),
+            is_sentence_boundary: bit(bits, 7),
+            is_sentence_start: bit(bits, 8),
+            is_sentence_end: bit(bits, 9),
+            backspace_deletes_character: bit(bits, 10),
+            is_expandable_space: bit(bits, 11),
+            is_word_boundary: bit(bits, 12),
+        }
+    }
+
+    fn to_bits(&self) -> u32 {
+        set_bit(self.is_line_break, 0) |
+        set_bit(self.is_mandatory_break, 1) |
+        set_bit(self.is_char_break, 2) |
+        set_bit(self.is_white, 3) |
+        set_bit(self.is_cursor_position, 4) |
+        set_bit(self.is_word_start, 5) |
+        set_bit(self.is_word_end, 6) |
+        set_bit(self.is_sentence_boundary, 7) |
+        set_bit(self.is_sentence_start, 8) |
+        set_bit(self.is_sentence_end, 9) |
+        set_bit(self.backspace_deletes_character, 10) |
+        set_bit(self.is_expandable_space, 11) |
+        set_bit(self.is_word_boundary, 12)
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrNone<*const ffi::PangoLogAttr> for LogAttr {
+    unsafe fn from_glib_none(ptr: *const ffi::PangoLogAttr) -> Self {
+        LogAttr::from_bits(*(ptr as *const u32))
+    }
+}
+
+#[doc(hidden)]
+impl FromGlibPtrNone<*mut ffi::PangoLogAttr> for LogAttr {
+    unsafe fn from_glib_none(ptr: *mut ffi::PangoLogAttr) -> Self {
+        from_glib_none(ptr as *const ffi::PangoLogAttr)
+    }
+}
+
+pub unsafe fn log_attrs_from_glib_none_num(ptr: *const ffi::PangoLogAttr, num: usize) -> Vec<LogAttr> {
+    let mut ret = Vec::with_capacity(num);
+    for i in 0..num {
+        ret.push(from_glib_none(ptr.offset(i as isize)));
+    }
+    ret
+}
+
+/// Packs `attrs` into a buffer that can be passed as a `PangoLogAttr` array.
+pub fn log_attrs_to_bits(attrs: &[LogAttr]) -> Vec<u32> {
+    attrs.iter().map(LogAttr::to_bits).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use get_log_attrs;
+    use Language;
+    use super::*;
+
+    // The positions for which `f` is true.
+    fn positions<F: Fn(&LogAttr) -> bool>(attrs: &[LogAttr], f: F) -> Vec<usize> {
+        attrs.iter().enumerate().filter(|&(_, attr)| f(attr)).map(|(i, _)| i).collect()
+    }
+
+    #[test]
+    fn hello_world() {
+        let attrs = get_log_attrs("Hello world.", -1, &Language::from_string("en"));
+        assert_eq!(attrs.len(), 13);
+        assert_eq!(positions(&attrs, |a| a.is_word_start), [0, 6]);
+        assert_eq!(positions(&attrs, |a| a.is_word_end), [5, 11]);
+        assert_eq!(positions(&attrs, |a| a.is_sentence_start), [0]);
+        assert_eq!(positions(&attrs, |a| a.is_sentence_end), [12]);
+        assert_eq!(positions(&attrs, |a| a.is_white), [5]);
+        assert_eq!(positions(&attrs, |a| a.is_cursor_position), (0..13).collect::<Vec<_>>());
+        assert_eq!(positions(&attrs[..12], |a| a.is_line_break), [6]);
+        assert!(positions(&attrs[..12], |a| a.is_mandatory_break).is_empty());
+    }
+
+    #[test]
+    fn bits_round_trip() {
+        let mut all = 0;
+        for n in 0..13 {
+            let bits = set_bit(true, n);
+            assert!(bit(bits, n));
+            assert_eq!(LogAttr::from_bits(bits).to_bits(), bits);
+            all |= bits;
+        }
+        assert_eq!(LogAttr::from_bits(all).to_bits(), all);
+        assert_eq!(LogAttr::from_bits(0), LogAttr::default());
+
+        for attr in get_log_attrs("Hello world.", -1, &Language::from_string("en")) {
+            assert_eq!(LogAttr::from_bits(attr.to_bits()), attr);
+        }
+    }
+}
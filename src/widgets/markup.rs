@@ -0,0 +1,287 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::error;
+use std::fmt;
+use std::ptr;
+
+use ffi;
+use glib;
+#[cfg(feature = "v1_31")]
+use glib_ffi;
+use glib::translate::*;
+#[cfg(feature = "v1_31")]
+use libc::{c_char, c_int};
+
+use AttrList;
+
+/// Parses Pango markup into the plain text and the attributes it describes.
+///
+/// If `accel_marker` is given, the character following its first occurrence
+/// is returned as the accelerator and the marker itself is removed from the
+/// text. A doubled marker produces a literal one.
+pub fn parse_markup(markup: &str, accel_marker: Option<char>)
+        -> Result<(AttrList, String, Option<char>), glib::Error> {
+    unsafe {
+        let mut attr_list = ptr::null_mut();
+        let mut text = ptr::null_mut();
+        let mut accel_char = 0;
+        let mut error = ptr::null_mut();
+        ffi::pango_parse_markup(markup.to_glib_none().0, markup.len() as i32, accel_marker_to_glib(accel_marker),
+                                &mut attr_list, &mut text, &mut accel_char, &mut error);
+        if error.is_null() {
+            Ok((from_glib_full(attr_list), from_glib_full(text), accel_char_from_glib(accel_char)))
+        } else {
+            Err(from_glib_full(error))
+        }
+    }
+}
+
+/// Parses `markup` like `parse_markup()`, with the position of an error
+/// relative to `markup` itself.
+#[cfg(feature = "v1_31")]
+pub fn parse_markup_checked(markup: &str, accel_marker: Option<char>)
+        -> Result<(AttrList, String, Option<char>), MarkupError> {
+    let mut parser = MarkupParser::new(accel_marker);
+    let ret = parser.parse(markup).and_then(|()| parser.finish_parse());
+    match ret {
+        Ok(ret) => Ok(ret),
+        Err(error) => {
+            // `pango_markup_parser_new()` already fed the root element.
+            let (line, column) = parser.position();
+            Err(MarkupError::new(error, Some(markup_position(line, column, ROOT.len() as u32))))
+        }
+    }
+}
+
+/// Parses `markup` like `parse_markup()`, with the position of an error
+/// relative to `markup` itself.
+#[cfg(not(feature = "v1_31"))]
+pub fn parse_markup_checked(markup: &str, accel_marker: Option<char>)
+        -> Result<(AttrList, String, Option<char>), MarkupError> {
+    parse_markup(markup, accel_marker).map_err(|error| {
+        // Pango 1.31 and later always wrap the markup in a root element,
+        // older versions only if it doesn't start with one.
+        let root_len = if unsafe { ffi::pango_version() } >= 13100 ||
+                          !markup.trim_left().starts_with(ROOT) {
+            ROOT.len() as u32
+        } else {
+            0
+        };
+        let full = error.to_string();
+        let position = split_position(&full).map(|(line, column, _)| markup_position(line, column, root_len));
+        MarkupError::new(error, position)
+    })
+}
+
+// The root element Pango parses the markup in.
+const ROOT: &'static str = "<markup>";
+
+// Maps a position in Pango's wrapped document to one in the user's markup.
+fn markup_position(line: u32, column: u32, root_len: u32) -> (u32, u32) {
+    if line == 1 && column > root_len {
+        (line, column - root_len)
+    } else {
+        (line, column)
+    }
+}
+
+/// Incremental markup parser for input that arrives in chunks.
+///
+/// Feed the markup with `parse()` and collect the result with `finish()`.
+#[cfg(feature = "v1_31")]
+pub struct MarkupParser(*mut glib_ffi::GMarkupParseContext);
+
+#[cfg(feature = "v1_31")]
+impl MarkupParser {
+    pub fn new(accel_marker: Option<char>) -> MarkupParser {
+        unsafe {
+            let tmp = ffi::pango_markup_parser_new(accel_marker_to_glib(accel_marker));
+            assert!(!tmp.is_null());
+            MarkupParser(tmp)
+        }
+    }
+
+    pub fn parse(&mut self, chunk: &str) -> Result<(), glib::Error> {
+        unsafe {
+            let mut error = ptr::null_mut();
+            glib_ffi::g_markup_parse_context_parse(self.0, chunk.as_ptr() as *const c_char,
+                                                   chunk.len() as isize, &mut error);
+            if error.is_null() { Ok(()) } else { Err(from_glib_full(error)) }
+        }
+    }
+
+    pub fn finish(mut self) -> Result<(AttrList, String, Option<char>), glib::Error> {
+        self.finish_parse()
+    }
+
+    fn finish_parse(&mut self) -> Result<(AttrList, String, Option<char>), glib::Error> {
+        unsafe {
+            let mut attr_list = ptr::null_mut();
+            let mut text = ptr::null_mut();
+            let mut accel_char = 0;
+            let mut error = ptr::null_mut();
+            ffi::pango_markup_parser_finish(self.0, &mut attr_list, &mut text, &mut accel_char, &mut error);
+            if error.is_null() {
+                Ok((from_glib_full(attr_list), from_glib_full(text), accel_char_from_glib(accel_char)))
+            } else {
+                Err(from_glib_full(error))
+            }
+        }
+    }
+
+    // The line and character GMarkup is at, both 1-based.
+    fn position(&self) -> (u32, u32) {
+        unsafe {
+            let mut line: c_int = 0;
+            let mut column: c_int = 0;
+            glib_ffi::g_markup_parse_context_get_position(self.0, &mut line, &mut column);
+            (line as u32, column as u32)
+        }
+    }
+}
+
+#[cfg(feature = "v1_31")]
+impl Drop for MarkupParser {
+    fn drop(&mut self) {
+        unsafe { glib_ffi::g_markup_parse_context_free(self.0) }
+    }
+}
+
+fn accel_marker_to_glib(accel_marker: Option<char>) -> u32 {
+    match accel_marker {
+        Some(c) => c.to_glib(),
+        None => 0,
+    }
+}
+
+fn accel_char_from_glib(accel_char: u32) -> Option<char> {
+    if accel_char == 0 { None } else { Some(from_glib(accel_char)) }
+}
+
+/// A markup parse failure with the position GMarkup reported for it.
+///
+/// The position is that of the character after the offending markup.
+/// Errors returned by `Layout::try_set_markup()` have it relative to the
+/// markup that was passed in, errors converted from a `glib::Error`
+/// relative to the document Pango parsed, which starts with an added
+/// `<markup>` element.
+#[derive(Debug)]
+pub struct MarkupError {
+    /// 1-based line of the offending markup, if known.
+    pub line: Option<u32>,
+    /// 1-based character on that line, if known.
+    pub column: Option<u32>,
+    /// The error message without the position.
+    pub message: String,
+    error: glib::Error,
+}
+
+impl MarkupError {
+    /// The underlying `GError` as returned by the parser.
+    pub fn get_error(&self) -> &glib::Error {
+        &self.error
+    }
+
+    fn new(error: glib::Error, position: Option<(u32, u32)>) -> MarkupError {
+        let full = error.to_string();
+        let (parsed, message) = match split_position(&full) {
+            Some((line, column, message)) => (Some((line, column)), message.to_owned()),
+            None => (None, full.clone()),
+        };
+        let position = position.or(parsed);
+        MarkupError {
+            line: position.map(|(line, _)| line),
+            column: position.map(|(_, column)| column),
+            message: message,
+            error: error,
+        }
+    }
+}
+
+impl From<glib::Error> for MarkupError {
+    fn from(error: glib::Error) -> MarkupError {
+        MarkupError::new(error, None)
+    }
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{}:{}: {}", line, column, self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl error::Error for MarkupError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+// GMarkup's own messages start with "Error on line N char M: ", the ones
+// Pango reports from its callbacks end with " on line N char M". Translated
+// messages aren't recognized.
+fn split_position(message: &str) -> Option<(u32, u32, &str)> {
+    const PREFIX: &'static str = "Error on line ";
+    const SUFFIX: &'static str = " on line ";
+    if message.starts_with(PREFIX) {
+        let mut parts = message[PREFIX.len()..].splitn(2, ": ");
+        let position = parts.next().and_then(parse_position);
+        match (position, parts.next()) {
+            (Some((line, column)), Some(message)) => Some((line, column, message)),
+            _ => None,
+        }
+    } else {
+        let start = match message.rfind(SUFFIX) {
+            Some(start) => start,
+            None => return None,
+        };
+        parse_position(&message[start + SUFFIX.len()..]).map(|(line, column)| (line, column, &message[..start]))
+    }
+}
+
+// Parses "N char M".
+fn parse_position(s: &str) -> Option<(u32, u32)> {
+    let mut parts = s.splitn(2, " char ");
+    let line = parts.next().and_then(|s| s.parse().ok());
+    let column = parts.next().and_then(|s| s.parse().ok());
+    match (line, column) {
+        (Some(line), Some(column)) => Some((line, column)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_position;
+
+    #[test]
+    fn split_position_gmarkup() {
+        assert_eq!(split_position("Error on line 1 char 17: Element 'markup' was closed, but the currently open \
+                                   element is 'b'"),
+                   Some((1, 17, "Element 'markup' was closed, but the currently open element is 'b'")));
+    }
+
+    #[test]
+    fn split_position_pango() {
+        assert_eq!(split_position("Unknown tag 'foo' on line 1 char 14"), Some((1, 14, "Unknown tag 'foo'")));
+        assert_eq!(split_position("Attribute 'bar' is not allowed on the <span> tag on line 2 char 21"),
+                   Some((2, 21, "Attribute 'bar' is not allowed on the <span> tag")));
+    }
+
+    #[test]
+    fn split_position_line_only() {
+        // Pango's messages for invalid attribute values only have a line.
+        assert_eq!(split_position("Value of 'size' attribute on <span> tag on line 1 could not be parsed; \
+                                   should be an integer, or a string such as 'small', not 'huge'"),
+                   None);
+    }
+
+    #[test]
+    fn split_position_translated() {
+        assert_eq!(split_position("Fehler in Zeile 3, Zeichen 14: Unbekanntes Tag »foo«"), None);
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::error;
+use std::fmt::{self, Write};
+
+use glib::translate::ToGlib;
+
+use {
+    Color,
+    FontDescription,
+    Gravity,
+    GravityHint,
+    Language,
+    Stretch,
+    Style,
+    Underline,
+    Variant,
+    Weight,
+};
+
+/// Escapes `text` so that it can be embedded in Pango markup.
+///
+/// This produces the same output as `g_markup_escape_text()`.
+pub fn escape_text(text: &str) -> String {
+    let mut ret = String::with_capacity(text.len());
+    escape_into(&mut ret, text);
+    ret
+}
+
+fn escape_into(buf: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '\'' => buf.push_str("&#39;"),
+            '"' => buf.push_str("&quot;"),
+            '\u{1}'...'\u{8}' | '\u{b}' | '\u{c}' | '\u{e}'...'\u{1f}' |
+            '\u{7f}'...'\u{84}' | '\u{86}'...'\u{9f}' => {
+                write!(buf, "&#x{:x};", c as u32).unwrap();
+            }
+            c => buf.push(c),
+        }
+    }
+}
+
+/// Attributes of a `<span>` element. Unset fields are not emitted.
+#[derive(Clone, Debug, Default)]
+pub struct SpanAttrs {
+    pub font_desc: Option<FontDescription>,
+    pub font_family: Option<String>,
+    /// Font size in Pango units, must be positive.
+    pub size: Option<i32>,
+    pub style: Option<Style>,
+    pub weight: Option<Weight>,
+    pub variant: Option<Variant>,
+    pub stretch: Option<Stretch>,
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub underline: Option<Underline>,
+    pub underline_color: Option<Color>,
+    /// Baseline displacement in Pango units.
+    pub rise: Option<i32>,
+    pub strikethrough: Option<bool>,
+    pub strikethrough_color: Option<Color>,
+    pub fallback: Option<bool>,
+    pub lang: Option<Language>,
+    /// Extra space between graphemes in Pango units.
+    pub letter_spacing: Option<i32>,
+    pub gravity: Option<Gravity>,
+    pub gravity_hint: Option<GravityHint>,
+}
+
+impl SpanAttrs {
+    fn render(&self, buf: &mut String) -> Result<(), InvalidSpanAttr> {
+        if let Some(ref desc) = self.font_desc {
+            push_attr(buf, "font_desc", &desc.to_string());
+        }
+        if let Some(ref family) = self.font_family {
+            push_attr(buf, "font_family", family);
+        }
+        if let Some(size) = self.size {
+            if size <= 0 {
+                return Err(InvalidSpanAttr("size"));
+            }
+            push_attr(buf, "size", &size.to_string());
+        }
+        if let Some(style) = self.style {
+            push_attr(buf, "style", try!(style_name(style).ok_or(InvalidSpanAttr("style"))));
+        }
+        if let Some(weight) = self.weight {
+            // Numeric weights are accepted by every Pango version, unlike
+            // some of the newer names.
+            try!(check(weight != Weight::__Nonexhaustive(()), "weight"));
+            push_attr(buf, "weight", &(weight.to_glib() as i32).to_string());
+        }
+        if let Some(variant) = self.variant {
+            push_attr(buf, "variant", try!(variant_name(variant).ok_or(InvalidSpanAttr("variant"))));
+        }
+        if let Some(stretch) = self.stretch {
+            push_attr(buf, "stretch", try!(stretch_name(stretch).ok_or(InvalidSpanAttr("stretch"))));
+        }
+        if let Some(color) = self.foreground {
+            push_attr(buf, "foreground", &color_spec(color));
+        }
+        if let Some(color) = self.background {
+            push_attr(buf, "background", &color_spec(color));
+        }
+        if let Some(underline) = self.underline {
+            push_attr(buf, "underline", try!(underline_name(underline).ok_or(InvalidSpanAttr("underline"))));
+        }
+        if let Some(color) = self.underline_color {
+            push_attr(buf, "underline_color", &color_spec(color));
+        }
+        if let Some(rise) = self.rise {
+            push_attr(buf, "rise", &rise.to_string());
+        }
+        if let Some(strikethrough) = self.strikethrough {
+            push_attr(buf, "strikethrough", bool_name(strikethrough));
+        }
+        if let Some(color) = self.strikethrough_color {
+            push_attr(buf, "strikethrough_color", &color_spec(color));
+        }
+        if let Some(fallback) = self.fallback {
+            push_attr(buf, "fallback", bool_name(fallback));
+        }
+        if let Some(ref lang) = self.lang {
+            push_attr(buf, "lang", &lang.to_string());
+        }
+        if let Some(letter_spacing) = self.letter_spacing {
+            push_attr(buf, "letter_spacing", &letter_spacing.to_string());
+        }
+        if let Some(gravity) = self.gravity {
+            push_attr(buf, "gravity", try!(gravity_name(gravity).ok_or(InvalidSpanAttr("gravity"))));
+        }
+        if let Some(hint) = self.gravity_hint {
+            push_attr(buf, "gravity_hint", try!(gravity_hint_name(hint).ok_or(InvalidSpanAttr("gravity_hint"))));
+        }
+        Ok(())
+    }
+}
+
+/// Returned by `Markup::render()` when a span attribute has no markup
+/// representation. Holds the name of the attribute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidSpanAttr(pub &'static str);
+
+impl fmt::Display for InvalidSpanAttr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid value for span attribute '{}'", self.0)
+    }
+}
+
+impl error::Error for InvalidSpanAttr {
+    fn description(&self) -> &str {
+        "invalid span attribute value"
+    }
+}
+
+/// Builds Pango markup with all text escaped.
+///
+/// ```ignore
+/// let markup = Markup::new()
+///     .text("Hello ")
+///     .span(SpanAttrs { weight: Some(Weight::Bold), ..Default::default() },
+///           |m| m.text(user_input))
+///     .render()
+///     .unwrap();
+/// layout.set_markup(&markup, -1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Markup {
+    buf: String,
+    error: Option<InvalidSpanAttr>,
+}
+
+impl Markup {
+    pub fn new() -> Markup {
+        Markup::default()
+    }
+
+    /// Appends `text`, escaping it.
+    pub fn text(mut self, text: &str) -> Markup {
+        escape_into(&mut self.buf, text);
+        self
+    }
+
+    /// Appends a `<span>` with the given attributes around the content
+    /// produced by `content`.
+    pub fn span<F: FnOnce(Markup) -> Markup>(mut self, attrs: SpanAttrs, content: F) -> Markup {
+        let mut open = String::from("<span");
+        if let Err(err) = attrs.render(&mut open) {
+            self.error = self.error.or(Some(err));
+        }
+        open.push('>');
+        self.nest(&open, "</span>", content)
+    }
+
+    pub fn bold<F: FnOnce(Markup) -> Markup>(self, content: F) -> Markup {
+        self.nest("<b>", "</b>", content)
+    }
+
+    pub fn italic<F: FnOnce(Markup) -> Markup>(self, content: F) -> Markup {
+        self.nest("<i>", "</i>", content)
+    }
+
+    pub fn monospace<F: FnOnce(Markup) -> Markup>(self, content: F) -> Markup {
+        self.nest("<tt>", "</tt>", content)
+    }
+
+    fn nest<F: FnOnce(Markup) -> Markup>(mut self, open: &str, close: &str, content: F) -> Markup {
+        let inner = content(Markup::new());
+        self.buf.push_str(open);
+        self.buf.push_str(&inner.buf);
+        self.buf.push_str(close);
+        self.error = self.error.or(inner.error);
+        self
+    }
+
+    /// Returns the markup, or the first attribute that could not be
+    /// represented.
+    pub fn render(self) -> Result<String, InvalidSpanAttr> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.buf),
+        }
+    }
+}
+
+fn push_attr(buf: &mut String, name: &str, value: &str) {
+    buf.push(' ');
+    buf.push_str(name);
+    buf.push_str("=\"");
+    escape_into(buf, value);
+    buf.push('"');
+}
+
+fn check(valid: bool, name: &'static str) -> Result<(), InvalidSpanAttr> {
+    if valid { Ok(()) } else { Err(InvalidSpanAttr(name)) }
+}
+
+fn color_spec(color: Color) -> String {
+    format!("#{:04x}{:04x}{:04x}", color.red, color.green, color.blue)
+}
+
+fn bool_name(value: bool) -> &'static str {
+    if value { "true" } else { "false" }
+}
+
+fn style_name(style: Style) -> Option<&'static str> {
+    match style {
+        Style::Normal => Some("normal"),
+        Style::Oblique => Some("oblique"),
+        Style::Italic => Some("italic"),
+        Style::__Nonexhaustive(_) => None,
+    }
+}
+
+fn variant_name(variant: Variant) -> Option<&'static str> {
+    match variant {
+        Variant::Normal => Some("normal"),
+        Variant::SmallCaps => Some("smallcaps"),
+        Variant::__Nonexhaustive(_) => None,
+    }
+}
+
+fn stretch_name(stretch: Stretch) -> Option<&'static str> {
+    match stretch {
+        Stretch::UltraCondensed => Some("ultracondensed"),
+        Stretch::ExtraCondensed => Some("extracondensed"),
+        Stretch::Condensed => Some("condensed"),
+        Stretch::SemiCondensed => Some("semicondensed"),
+        Stretch::Normal => Some("normal"),
+        Stretch::SemiExpanded => Some("semiexpanded"),
+        Stretch::Expanded => Some("expanded"),
+        Stretch::ExtraExpanded => Some("extraexpanded"),
+        Stretch::UltraExpanded => Some("ultraexpanded"),
+        Stretch::__Nonexhaustive(_) => None,
+    }
+}
+
+fn underline_name(underline: Underline) -> Option<&'static str> {
+    match underline {
+        Underline::None => Some("none"),
+        Underline::Single => Some("single"),
+        Underline::Double => Some("double"),
+        Underline::Low => Some("low"),
+        Underline::Error => Some("error"),
+        Underline::__Nonexhaustive(_) => None,
+    }
+}
+
+fn gravity_name(gravity: Gravity) -> Option<&'static str> {
+    match gravity {
+        Gravity::South => Some("south"),
+        Gravity::East => Some("east"),
+        Gravity::North => Some("north"),
+        Gravity::West => Some("west"),
+        Gravity::Auto => Some("auto"),
+        Gravity::__Nonexhaustive(_) => None,
+    }
+}
+
+fn gravity_hint_name(hint: GravityHint) -> Option<&'static str> {
+    match hint {
+        GravityHint::Natural => Some("natural"),
+        GravityHint::Strong => Some("strong"),
+        GravityHint::Line => Some("line"),
+        GravityHint::__Nonexhaustive(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {parse_markup, AttrKind, Color, Style, Weight};
+
+    #[test]
+    fn escape_special_characters() {
+        assert_eq!(escape_text("<a href=\"x\">Tom & Jerry's</a>"),
+                   "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;");
+    }
+
+    #[test]
+    fn escape_control_characters() {
+        assert_eq!(escape_text("a\u{1}b\u{1f}c\u{7f}d\u{85}e\u{9f}"), "a&#x1;b&#x1f;c&#x7f;d\u{85}e&#x9f;");
+        // Tab, newline and carriage return are kept.
+        assert_eq!(escape_text("a\tb\nc\rd"), "a\tb\nc\rd");
+    }
+
+    #[test]
+    fn reject_nonexhaustive_values() {
+        let attrs = SpanAttrs { style: Some(Style::__Nonexhaustive(())), ..SpanAttrs::default() };
+        assert_eq!(Markup::new().span(attrs, |m| m.text("x")).render(), Err(InvalidSpanAttr("style")));
+
+        let attrs = SpanAttrs { weight: Some(Weight::__Nonexhaustive(())), ..SpanAttrs::default() };
+        assert_eq!(Markup::new().span(attrs, |m| m.text("x")).render(), Err(InvalidSpanAttr("weight")));
+    }
+
+    #[test]
+    fn reject_non_positive_size() {
+        let attrs = SpanAttrs { size: Some(0), ..SpanAttrs::default() };
+        assert_eq!(Markup::new().span(attrs, |m| m.text("x")).render(), Err(InvalidSpanAttr("size")));
+    }
+
+    #[test]
+    fn render_round_trips_through_parser() {
+        let red = Color::new(0xffff, 0, 0);
+        let attrs = SpanAttrs {
+            weight: Some(Weight::Bold),
+            foreground: Some(red),
+            ..SpanAttrs::default()
+        };
+        let markup = Markup::new()
+            .text("a < b & ")
+            .span(attrs, |m| m.text("\"c\"").italic(|m| m.text("'d'")))
+            .render()
+            .unwrap();
+
+        let (attr_list, text, accel) = parse_markup(&markup, None).unwrap();
+        assert_eq!(text, "a < b & \"c\"'d'");
+        assert_eq!(accel, None);
+
+        let mut attrs = Vec::new();
+        attr_list.filter(|attr| {
+            attrs.push((attr.get_start_index(), attr.get_end_index(), attr.kind()));
+            false
+        });
+        assert!(attrs.contains(&(8, 14, Some(AttrKind::Weight(Weight::Bold)))));
+        assert!(attrs.contains(&(8, 14, Some(AttrKind::Foreground(red)))));
+        assert!(attrs.contains(&(11, 14, Some(AttrKind::Style(Style::Italic)))));
+        assert_eq!(attrs.len(), 3);
+    }
+}
@@ -2,14 +2,63 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
+pub use self::attr_iterator::AttrIterator;
+pub use self::attr_kind::{AttrKind, InvalidAttrKind};
+pub use self::attribute::Attribute;
+pub use self::color::Color;
 pub use self::item::Item;
 pub use self::rectangle::Rectangle;
 pub use self::matrix::Matrix;
-pub use self::glyph_string::GlyphString;
+pub use self::analysis::{Analysis, ANALYSIS_FLAG_CENTERED_BASELINE, ANALYSIS_FLAG_IS_ELLIPSIS};
+pub use self::glyph_string::{GlyphGeometry, GlyphInfo, GlyphString, GLYPH_EMPTY, GLYPH_UNKNOWN_FLAG};
+pub use self::glyph_item::LayoutRun;
+pub use self::glyph_item_iter::{ClusterInfo, GlyphItemIter};
 pub use self::font_description::FontDescription;
+pub use self::itemize::{itemize, itemize_with_base_dir, reorder_items, visual_order};
+pub use self::language::Language;
+pub use self::layout_snapshot::{AttrSnapshot, GlyphSnapshot, LayoutSnapshot, LineSnapshot, RunSnapshot};
+pub use self::log_attr::LogAttr;
+pub use self::markup::{parse_markup, MarkupError};
+#[cfg(feature = "v1_31")]
+pub use self::markup::MarkupParser;
+pub use self::markup_builder::{escape_text, InvalidSpanAttr, Markup, SpanAttrs};
+pub use self::renderer::{RendererImpl, RendererImplExt};
+pub use self::shape::shape;
+#[cfg(feature = "v1_32")]
+pub use self::shape::shape_full;
+pub use self::segmentation::{default_break, get_log_attrs, TextBreaks};
+pub use self::svg::{GlyphOutlines, SvgExport};
 
+mod analysis;
+mod attr_iterator;
+mod attr_kind;
+mod attr_list;
+mod attribute;
+mod callback;
+mod color;
+mod coverage;
 mod item;
+mod itemize;
 mod rectangle;
 mod matrix;
 mod glyph_string;
+mod glyph_item;
+mod glyph_item_iter;
+mod segmentation;
+mod shape;
+mod svg;
+mod tab_array;
+#[cfg(test)]
+mod test_util;
 mod font_description;
+mod font_face;
+mod font_family;
+mod fontset;
+mod language;
+mod layout;
+mod layout_line;
+mod layout_snapshot;
+mod log_attr;
+mod markup;
+mod markup_builder;
+mod renderer;
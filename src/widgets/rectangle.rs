@@ -7,6 +7,7 @@ use glib::translate::*;
 use ffi;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 #[repr(C)]
 pub struct Rectangle {
     pub x: i32,
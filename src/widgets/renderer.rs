@@ -0,0 +1,393 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::any::TypeId;
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::sync::{Once, ONCE_INIT};
+
+use ffi;
+use glib_ffi;
+use gobject_ffi;
+use glib::translate::*;
+use libc::c_int;
+
+use Attribute;
+use Font;
+use GlyphString;
+use LayoutRun;
+use Matrix;
+use RenderPart;
+use Renderer;
+use super::callback::call_from_c;
+
+/// The drawing operations of a `Renderer` implemented in Rust.
+///
+/// Every method defaults to the behaviour of the `PangoRenderer` base
+/// class, which is also available from `RendererImplExt` to chain up from
+/// an override. At a minimum `draw_glyph()` or `draw_glyphs()` and
+/// `draw_trapezoid()` or `draw_rectangle()` have to be implemented for
+/// anything to be drawn.
+///
+/// Coordinates are in Pango units unless the method takes `f64`s, which
+/// are in device units. The current colors and matrix are available from
+/// `renderer`, the implementation itself from `Renderer::get_impl()`.
+///
+/// The methods are called from Pango's C code, a panic in them aborts the
+/// process.
+///
+/// ```ignore
+/// struct Counter(Cell<usize>);
+///
+/// impl RendererImpl for Counter {
+///     fn draw_glyph(&self, _: &Renderer, _: &Font, _: u32, _: f64, _: f64) {
+///         self.0.set(self.0.get() + 1);
+///     }
+/// }
+///
+/// let renderer = Renderer::with_impl(Counter(Cell::new(0)));
+/// renderer.draw_layout(&layout, 0, 0);
+/// let glyphs = renderer.get_impl::<Counter>().unwrap().0.get();
+/// ```
+pub trait RendererImpl: 'static {
+    fn draw_glyphs(&self, renderer: &Renderer, font: &Font, glyphs: &GlyphString, x: i32, y: i32) {
+        self.parent_draw_glyphs(renderer, font, glyphs, x, y)
+    }
+
+    fn draw_rectangle(&self, renderer: &Renderer, part: RenderPart, x: i32, y: i32, width: i32, height: i32) {
+        self.parent_draw_rectangle(renderer, part, x, y, width, height)
+    }
+
+    fn draw_error_underline(&self, renderer: &Renderer, x: i32, y: i32, width: i32, height: i32) {
+        self.parent_draw_error_underline(renderer, x, y, width, height)
+    }
+
+    /// Draws the box of a shape attribute, which is passed as `attr`.
+    fn draw_shape(&self, renderer: &Renderer, attr: &Attribute, x: i32, y: i32) {
+        self.parent_draw_shape(renderer, attr, x, y)
+    }
+
+    fn draw_trapezoid(&self, renderer: &Renderer, part: RenderPart, y1: f64, x11: f64, x21: f64,
+                      y2: f64, x12: f64, x22: f64) {
+        self.parent_draw_trapezoid(renderer, part, y1, x11, x21, y2, x12, x22)
+    }
+
+    fn draw_glyph(&self, renderer: &Renderer, font: &Font, glyph: u32, x: f64, y: f64) {
+        self.parent_draw_glyph(renderer, font, glyph, x, y)
+    }
+
+    /// Called when the color of `part` changed during drawing.
+    fn part_changed(&self, renderer: &Renderer, part: RenderPart) {
+        self.parent_part_changed(renderer, part)
+    }
+
+    fn begin(&self, renderer: &Renderer) {
+        self.parent_begin(renderer)
+    }
+
+    fn end(&self, renderer: &Renderer) {
+        self.parent_end(renderer)
+    }
+
+    /// Called before each run is drawn. The base class sets the colors,
+    /// underline and strikethrough of the renderer from the attributes of
+    /// the run.
+    fn prepare_run(&self, renderer: &Renderer, run: &LayoutRun) {
+        self.parent_prepare_run(renderer, run)
+    }
+}
+
+/// Calls the `PangoRenderer` base class implementation of the drawing
+/// operations from a `RendererImpl`.
+///
+/// `renderer` must be the one passed to the `RendererImpl` method, i.e.
+/// one created by `Renderer::with_impl()`.
+pub trait RendererImplExt {
+    fn parent_draw_glyphs(&self, renderer: &Renderer, font: &Font, glyphs: &GlyphString, x: i32, y: i32);
+
+    fn parent_draw_rectangle(&self, renderer: &Renderer, part: RenderPart, x: i32, y: i32, width: i32,
+                             height: i32);
+
+    fn parent_draw_error_underline(&self, renderer: &Renderer, x: i32, y: i32, width: i32, height: i32);
+
+    fn parent_draw_shape(&self, renderer: &Renderer, attr: &Attribute, x: i32, y: i32);
+
+    fn parent_draw_trapezoid(&self, renderer: &Renderer, part: RenderPart, y1: f64, x11: f64, x21: f64,
+                             y2: f64, x12: f64, x22: f64);
+
+    fn parent_draw_glyph(&self, renderer: &Renderer, font: &Font, glyph: u32, x: f64, y: f64);
+
+    fn parent_part_changed(&self, renderer: &Renderer, part: RenderPart);
+
+    fn parent_begin(&self, renderer: &Renderer);
+
+    fn parent_end(&self, renderer: &Renderer);
+
+    fn parent_prepare_run(&self, renderer: &Renderer, run: &LayoutRun);
+}
+
+impl<T: RendererImpl + ?Sized> RendererImplExt for T {
+    fn parent_draw_glyphs(&self, renderer: &Renderer, font: &Font, glyphs: &GlyphString, x: i32, y: i32) {
+        unsafe {
+            if let Some(f) = (*parent_class(renderer)).draw_glyphs {
+                f(renderer.to_glib_none().0, font.to_glib_none().0, mut_override(glyphs.to_glib_none().0), x, y);
+            }
+        }
+    }
+
+    fn parent_draw_rectangle(&self, renderer: &Renderer, part: RenderPart, x: i32, y: i32, width: i32,
+                             height: i32) {
+        unsafe {
+            if let Some(f) = (*parent_class(renderer)).draw_rectangle {
+                f(renderer.to_glib_none().0, part.to_glib(), x, y, width, height);
+            }
+        }
+    }
+
+    fn parent_draw_error_underline(&self, renderer: &Renderer, x: i32, y: i32, width: i32, height: i32) {
+        unsafe {
+            if let Some(f) = (*parent_class(renderer)).draw_error_underline {
+                f(renderer.to_glib_none().0, x, y, width, height);
+            }
+        }
+    }
+
+    fn parent_draw_shape(&self, renderer: &Renderer, attr: &Attribute, x: i32, y: i32) {
+        unsafe {
+            if let Some(f) = (*parent_class(renderer)).draw_shape {
+                let attr: *mut ffi::PangoAttribute = attr.to_glib_none().0;
+                f(renderer.to_glib_none().0, attr as *mut ffi::PangoAttrShape, x, y);
+            }
+        }
+    }
+
+    fn parent_draw_trapezoid(&self, renderer: &Renderer, part: RenderPart, y1: f64, x11: f64, x21: f64,
+                             y2: f64, x12: f64, x22: f64) {
+        unsafe {
+            if let Some(f) = (*parent_class(renderer)).draw_trapezoid {
+                f(renderer.to_glib_none().0, part.to_glib(), y1, x11, x21, y2, x12, x22);
+            }
+        }
+    }
+
+    fn parent_draw_glyph(&self, renderer: &Renderer, font: &Font, glyph: u32, x: f64, y: f64) {
+        unsafe {
+            if let Some(f) = (*parent_class(renderer)).draw_glyph {
+                f(renderer.to_glib_none().0, font.to_glib_none().0, glyph, x, y);
+            }
+        }
+    }
+
+    fn parent_part_changed(&self, renderer: &Renderer, part: RenderPart) {
+        unsafe {
+            if let Some(f) = (*parent_class(renderer)).part_changed {
+                f(renderer.to_glib_none().0, part.to_glib());
+            }
+        }
+    }
+
+    fn parent_begin(&self, renderer: &Renderer) {
+        unsafe {
+            if let Some(f) = (*parent_class(renderer)).begin {
+                f(renderer.to_glib_none().0);
+            }
+        }
+    }
+
+    fn parent_end(&self, renderer: &Renderer) {
+        unsafe {
+            if let Some(f) = (*parent_class(renderer)).end {
+                f(renderer.to_glib_none().0);
+            }
+        }
+    }
+
+    fn parent_prepare_run(&self, renderer: &Renderer, run: &LayoutRun) {
+        unsafe {
+            if let Some(f) = (*parent_class(renderer)).prepare_run {
+                f(renderer.to_glib_none().0, mut_override(run.to_glib_none().0));
+            }
+        }
+    }
+}
+
+#[repr(C)]
+struct RustRenderer {
+    parent: ffi::PangoRenderer,
+    imp: *mut Box<RendererImpl>,
+    imp_type: TypeId,
+}
+
+impl Renderer {
+    /// Creates a renderer that forwards its drawing operations to `imp`.
+    pub fn with_impl<T: RendererImpl>(imp: T) -> Renderer {
+        unsafe {
+            let ptr = gobject_ffi::g_object_new(rust_renderer_get_type(), ptr::null()) as *mut RustRenderer;
+            let imp: Box<RendererImpl> = Box::new(imp);
+            (*ptr).imp = Box::into_raw(Box::new(imp));
+            (*ptr).imp_type = TypeId::of::<T>();
+            from_glib_full(ptr as *mut ffi::PangoRenderer)
+        }
+    }
+
+    /// Returns the implementation the renderer was created with, or `None`
+    /// if it wasn't created by `with_impl()` with a `T`.
+    pub fn get_impl<T: RendererImpl>(&self) -> Option<&T> {
+        unsafe {
+            let ptr: *mut ffi::PangoRenderer = self.to_glib_none().0;
+            if !is_rust_renderer(ptr) {
+                return None;
+            }
+            let renderer = ptr as *const RustRenderer;
+            if (*renderer).imp.is_null() || (*renderer).imp_type != TypeId::of::<T>() {
+                return None;
+            }
+            Some(&*(&**(*renderer).imp as *const RendererImpl as *const T))
+        }
+    }
+
+    /// Sets the transformation from user space to device space, `None`
+    /// for the identity.
+    pub fn set_matrix(&self, matrix: Option<&ffi::PangoMatrix>) {
+        unsafe {
+            let matrix = matrix.map_or(ptr::null(), |m| m as *const _);
+            ffi::pango_renderer_set_matrix(self.to_glib_none().0, matrix);
+        }
+    }
+
+    pub fn get_matrix(&self) -> Option<ffi::PangoMatrix> {
+        unsafe {
+            let matrix = ffi::pango_renderer_get_matrix(self.to_glib_none().0);
+            if matrix.is_null() { None } else { Some((*matrix).copy()) }
+        }
+    }
+}
+
+// The class `RustRenderer` derives from, only valid for its instances.
+unsafe fn parent_class(renderer: &Renderer) -> *const ffi::PangoRendererClass {
+    assert!(is_rust_renderer(renderer.to_glib_none().0), "renderer was not created by Renderer::with_impl()");
+    base_class()
+}
+
+unsafe fn is_rust_renderer(ptr: *mut ffi::PangoRenderer) -> bool {
+    from_glib(gobject_ffi::g_type_check_instance_is_a(ptr as *mut gobject_ffi::GTypeInstance,
+                                                      rust_renderer_get_type()))
+}
+
+unsafe fn base_class() -> *const ffi::PangoRendererClass {
+    gobject_ffi::g_type_class_peek(ffi::pango_renderer_get_type()) as *const _
+}
+
+fn rust_renderer_get_type() -> glib_ffi::GType {
+    static mut TYPE: glib_ffi::GType = 0;
+    static ONCE: Once = ONCE_INIT;
+
+    unsafe {
+        ONCE.call_once(|| {
+            // Make sure the parent class exists for `base_class()`.
+            gobject_ffi::g_type_class_ref(ffi::pango_renderer_get_type());
+            TYPE = gobject_ffi::g_type_register_static_simple(
+                ffi::pango_renderer_get_type(), b"PangoRsRenderer\0".as_ptr() as *const _,
+                ::std::mem::size_of::<ffi::PangoRendererClass>() as u32, Some(class_init),
+                ::std::mem::size_of::<RustRenderer>() as u32, None, gobject_ffi::GTypeFlags::empty());
+        });
+        TYPE
+    }
+}
+
+unsafe extern "C" fn class_init(klass: glib_ffi::gpointer, _: glib_ffi::gpointer) {
+    let object_class = klass as *mut gobject_ffi::GObjectClass;
+    (*object_class).finalize = Some(finalize);
+
+    let klass = klass as *mut ffi::PangoRendererClass;
+    (*klass).draw_glyphs = Some(draw_glyphs);
+    (*klass).draw_rectangle = Some(draw_rectangle);
+    (*klass).draw_error_underline = Some(draw_error_underline);
+    (*klass).draw_shape = Some(draw_shape);
+    (*klass).draw_trapezoid = Some(draw_trapezoid);
+    (*klass).draw_glyph = Some(draw_glyph);
+    (*klass).part_changed = Some(part_changed);
+    (*klass).begin = Some(begin);
+    (*klass).end = Some(end);
+    (*klass).prepare_run = Some(prepare_run);
+}
+
+unsafe extern "C" fn finalize(object: *mut gobject_ffi::GObject) {
+    let renderer = object as *mut RustRenderer;
+    if !(*renderer).imp.is_null() {
+        let imp = Box::from_raw((*renderer).imp);
+        (*renderer).imp = ptr::null_mut();
+        call_from_c(move || drop(imp));
+    }
+    let parent = base_class() as *const gobject_ffi::GObjectClass;
+    if let Some(f) = (*parent).finalize {
+        f(object);
+    }
+}
+
+unsafe fn with_imp<F: FnOnce(&RendererImpl, &Renderer)>(renderer: *mut ffi::PangoRenderer, f: F) {
+    let imp = (*(renderer as *mut RustRenderer)).imp;
+    // Nothing is drawn before `with_impl()` returns.
+    if imp.is_null() {
+        return;
+    }
+    call_from_c(|| f(&**imp, &from_glib_none(renderer)))
+}
+
+// Wraps a boxed value Pango passes to a vfunc without copying it, Pango
+// keeps ownership.
+unsafe fn borrow<P, T: FromGlibPtrFull<P>>(ptr: P) -> ManuallyDrop<T> {
+    ManuallyDrop::new(from_glib_full(ptr))
+}
+
+unsafe extern "C" fn draw_glyphs(renderer: *mut ffi::PangoRenderer, font: *mut ffi::PangoFont,
+                                 glyphs: *mut ffi::PangoGlyphString, x: c_int, y: c_int) {
+    with_imp(renderer, |imp, renderer| {
+        imp.draw_glyphs(renderer, &from_glib_none(font), &borrow::<_, GlyphString>(glyphs), x, y)
+    })
+}
+
+unsafe extern "C" fn draw_rectangle(renderer: *mut ffi::PangoRenderer, part: ffi::PangoRenderPart,
+                                    x: c_int, y: c_int, width: c_int, height: c_int) {
+    with_imp(renderer, |imp, renderer| imp.draw_rectangle(renderer, from_glib(part), x, y, width, height))
+}
+
+unsafe extern "C" fn draw_error_underline(renderer: *mut ffi::PangoRenderer, x: c_int, y: c_int, width: c_int,
+                                          height: c_int) {
+    with_imp(renderer, |imp, renderer| imp.draw_error_underline(renderer, x, y, width, height))
+}
+
+unsafe extern "C" fn draw_shape(renderer: *mut ffi::PangoRenderer, attr: *mut ffi::PangoAttrShape, x: c_int,
+                                y: c_int) {
+    with_imp(renderer, |imp, renderer| {
+        let attr: ManuallyDrop<Attribute> = borrow(attr as *mut ffi::PangoAttribute);
+        imp.draw_shape(renderer, &attr, x, y)
+    })
+}
+
+unsafe extern "C" fn draw_trapezoid(renderer: *mut ffi::PangoRenderer, part: ffi::PangoRenderPart, y1: f64,
+                                    x11: f64, x21: f64, y2: f64, x12: f64, x22: f64) {
+    with_imp(renderer, |imp, renderer| imp.draw_trapezoid(renderer, from_glib(part), y1, x11, x21, y2, x12, x22))
+}
+
+unsafe extern "C" fn draw_glyph(renderer: *mut ffi::PangoRenderer, font: *mut ffi::PangoFont,
+                                glyph: ffi::PangoGlyph, x: f64, y: f64) {
+    with_imp(renderer, |imp, renderer| imp.draw_glyph(renderer, &from_glib_none(font), glyph, x, y))
+}
+
+unsafe extern "C" fn part_changed(renderer: *mut ffi::PangoRenderer, part: ffi::PangoRenderPart) {
+    with_imp(renderer, |imp, renderer| imp.part_changed(renderer, from_glib(part)))
+}
+
+unsafe extern "C" fn begin(renderer: *mut ffi::PangoRenderer) {
+    with_imp(renderer, |imp, renderer| imp.begin(renderer))
+}
+
+unsafe extern "C" fn end(renderer: *mut ffi::PangoRenderer) {
+    with_imp(renderer, |imp, renderer| imp.end(renderer))
+}
+
+unsafe extern "C" fn prepare_run(renderer: *mut ffi::PangoRenderer, run: *mut ffi::PangoLayoutRun) {
+    with_imp(renderer, |imp, renderer| imp.prepare_run(renderer, &borrow::<_, LayoutRun>(run)))
+}
@@ -0,0 +1,119 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::ptr;
+use std::vec;
+
+use ffi;
+use glib::translate::*;
+
+use Language;
+use LogAttr;
+use super::log_attr::log_attrs_from_glib_none_num;
+
+/// Computes the boundary information for `text` without a `Layout`.
+///
+/// `level` is the bidi embedding level of the text, or -1 if unknown. The
+/// result has one entry per character plus one for the end of the text.
+pub fn get_log_attrs(text: &str, level: i32, language: &Language) -> Vec<LogAttr> {
+    let attrs_len = text.chars().count() + 1;
+    let mut attrs = vec![0u32; attrs_len];
+    unsafe {
+        let attrs_ptr = attrs.as_mut_ptr() as *mut ffi::PangoLogAttr;
+        ffi::pango_get_log_attrs(text.to_glib_none().0, text.len() as i32, level, language.to_glib_none().0,
+                                 attrs_ptr, attrs_len as i32);
+        log_attrs_from_glib_none_num(attrs_ptr, attrs_len)
+    }
+}
+
+/// Runs only the Unicode default breaking algorithm on `text`, ignoring
+/// language and script specific rules.
+pub fn default_break(text: &str) -> Vec<LogAttr> {
+    let attrs_len = text.chars().count() + 1;
+    let mut attrs = vec![0u32; attrs_len];
+    unsafe {
+        let attrs_ptr = attrs.as_mut_ptr() as *mut ffi::PangoLogAttr;
+        ffi::pango_default_break(text.to_glib_none().0, text.len() as i32, ptr::null_mut(),
+                                 attrs_ptr, attrs_len as i32);
+        log_attrs_from_glib_none_num(attrs_ptr, attrs_len)
+    }
+}
+
+/// Splits a string into grapheme clusters, words and sentences following
+/// Pango's segmentation rules.
+///
+/// ```ignore
+/// let breaks = TextBreaks::new("Hello world. Bye.", &Language::from_string("en"));
+/// assert_eq!(breaks.words().collect::<Vec<_>>(), ["Hello", "world", "Bye"]);
+/// ```
+pub struct TextBreaks<'a> {
+    text: &'a str,
+    attrs: Vec<LogAttr>,
+    // Byte offset of every character position, including the end.
+    offsets: Vec<usize>,
+}
+
+impl<'a> TextBreaks<'a> {
+    pub fn new(text: &'a str, language: &Language) -> TextBreaks<'a> {
+        let attrs = get_log_attrs(text, -1, language);
+        let mut offsets: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        offsets.push(text.len());
+        TextBreaks {
+            text: text,
+            attrs: attrs,
+            offsets: offsets,
+        }
+    }
+
+    pub fn log_attrs(&self) -> &[LogAttr] {
+        &self.attrs
+    }
+
+    /// Iterates over user-perceived characters.
+    pub fn graphemes(&self) -> vec::IntoIter<&'a str> {
+        let mut ret = Vec::new();
+        let mut start = 0;
+        for i in 1..self.attrs.len() {
+            if self.attrs[i].is_cursor_position || i == self.attrs.len() - 1 {
+                ret.push(self.slice(start, i));
+                start = i;
+            }
+        }
+        ret.into_iter()
+    }
+
+    /// Iterates over words, skipping the whitespace and punctuation
+    /// between them.
+    pub fn words(&self) -> vec::IntoIter<&'a str> {
+        self.spans(|attr| attr.is_word_start, |attr| attr.is_word_end)
+    }
+
+    /// Iterates over sentences, without the whitespace between them.
+    pub fn sentences(&self) -> vec::IntoIter<&'a str> {
+        self.spans(|attr| attr.is_sentence_start, |attr| attr.is_sentence_end)
+    }
+
+    fn spans<S, E>(&self, is_start: S, is_end: E) -> vec::IntoIter<&'a str>
+            where S: Fn(&LogAttr) -> bool, E: Fn(&LogAttr) -> bool {
+        let mut ret = Vec::new();
+        let mut start = None;
+        for (i, attr) in self.attrs.iter().enumerate() {
+            // An end and a new start can share a position.
+            if let Some(s) = start {
+                if is_end(attr) {
+                    ret.push(self.slice(s, i));
+                    start = None;
+                }
+            }
+            if start.is_none() && is_start(attr) {
+                start = Some(i);
+            }
+        }
+        ret.into_iter()
+    }
+
+    fn slice(&self, start: usize, end: usize) -> &'a str {
+        &self.text[self.offsets[start]..self.offsets[end]]
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use ffi;
+use glib::translate::*;
+
+use Analysis;
+use GlyphString;
+
+/// Converts the text of a single item into positioned glyphs.
+///
+/// `text` must be the item's text only, i.e. the `length()` bytes starting
+/// at its `offset()`, and `analysis` the item's analysis.
+pub fn shape(text: &str, analysis: &Analysis, glyphs: &mut GlyphString) {
+    unsafe {
+        ffi::pango_shape(text.to_glib_none().0, text.len() as i32, analysis.to_glib_ptr(),
+                         glyphs.to_glib_none_mut().0);
+    }
+}
+
+/// Like `shape()`, but also passes the whole paragraph so the shaper can
+/// take the surrounding text into account, e.g. for Arabic joining.
+///
+/// `item_text` must be a subslice of `paragraph_text`.
+#[cfg(feature = "v1_32")]
+pub fn shape_full(item_text: &str, paragraph_text: &str, analysis: &Analysis, glyphs: &mut GlyphString) {
+    let paragraph_start = paragraph_text.as_ptr() as usize;
+    let item_start = item_text.as_ptr() as usize;
+    assert!(item_start >= paragraph_start &&
+            item_start + item_text.len() <= paragraph_start + paragraph_text.len(),
+            "item_text is not part of paragraph_text");
+    let offset = (item_start - paragraph_start) as isize;
+    unsafe {
+        // Pango locates the item inside the paragraph by pointer, so both
+        // must point into the same buffer.
+        let paragraph = paragraph_text.to_glib_none();
+        ffi::pango_shape_full(paragraph.0.offset(offset), item_text.len() as i32,
+                              paragraph.0, paragraph_text.len() as i32,
+                              analysis.to_glib_ptr(), glyphs.to_glib_none_mut().0);
+    }
+}
@@ -0,0 +1,328 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::fmt::Write;
+
+use glib::translate::ToGlib;
+
+use {
+    AttrKind,
+    Attribute,
+    Color,
+    Font,
+    FontExt,
+    Layout,
+    LayoutRun,
+    Rectangle,
+    Style,
+    Underline,
+    Variant,
+    ANALYSIS_FLAG_IS_ELLIPSIS,
+    GLYPH_EMPTY,
+    GLYPH_UNKNOWN_FLAG,
+    SCALE,
+};
+use super::markup_builder::escape_text;
+
+/// Supplies glyph outlines to `SvgExport`, Pango itself has no API to
+/// retrieve them.
+pub trait GlyphOutlines {
+    /// Returns the outline of `glyph` as SVG path data in pixels, with the
+    /// glyph origin at (0, 0) and y growing downwards, or `None` if the glyph
+    /// has nothing to draw.
+    fn glyph_path(&self, font: &Font, glyph: u32) -> Option<String>;
+}
+
+/// Converts a `Layout` into an SVG document.
+///
+/// Every cluster is emitted as a `<tspan>` at the position Pango shaped it
+/// to, so the result only depends on the fonts of the layout's font map
+/// and not on any graphics backend. With `outlines()` the glyphs are
+/// emitted as `<path>` elements instead, which doesn't require the viewer
+/// to have the fonts.
+///
+/// ```ignore
+/// let svg = SvgExport::new().render(&layout);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct SvgExport<'a> {
+    outlines: Option<&'a GlyphOutlines>,
+}
+
+impl<'a> SvgExport<'a> {
+    pub fn new() -> SvgExport<'a> {
+        SvgExport::default()
+    }
+
+    /// Emits glyph outlines from `outlines` instead of text.
+    pub fn outlines(mut self, outlines: &'a GlyphOutlines) -> SvgExport<'a> {
+        self.outlines = Some(outlines);
+        self
+    }
+
+    /// Returns the SVG document for `layout`, sized to its logical extents.
+    pub fn render(&self, layout: &Layout) -> String {
+        let (_, extents) = layout.get_extents();
+        let mut svg = String::new();
+        write!(svg, "<svg xmlns=\"http://www.w3.org/2000/svg\" xml:space=\"preserve\" width=\"{}\" height=\"{}\" \
+                     viewBox=\"{} {} {} {}\">\n",
+               px(extents.width), px(extents.height),
+               px(extents.x), px(extents.y), px(extents.width), px(extents.height)).unwrap();
+
+        let text = layout.get_text().unwrap_or_default();
+        if let Some(mut iter) = layout.get_iter() {
+            loop {
+                // Lines end with a run-less position.
+                if let Some(run) = iter.get_run_readonly() {
+                    let (_, logical) = iter.get_run_extents();
+                    let baseline = iter.get_baseline();
+                    self.render_run(&mut svg, &text, &run, &logical, baseline);
+                }
+                if !iter.next_run() {
+                    break;
+                }
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn render_run(&self, svg: &mut String, text: &str, run: &LayoutRun, logical: &Rectangle, baseline: i32) {
+        let item = run.item();
+        let analysis = item.analysis();
+        let font = match analysis.font() {
+            Some(font) => font,
+            None => return,
+        };
+        let style = RunStyle::new(&analysis.extra_attrs());
+        let baseline = baseline - style.rise;
+        let glyph_string = run.glyphs();
+        let glyphs = glyph_string.glyphs();
+
+        if let Some(color) = style.background {
+            push_rect(svg, logical.x, logical.y, logical.width, logical.height, Some(color));
+        }
+
+        // Glyph origins in visual order.
+        let mut origins = Vec::with_capacity(glyphs.len());
+        let mut x = logical.x;
+        for info in glyphs {
+            origins.push((x + info.geometry.x_offset, baseline + info.geometry.y_offset));
+            x += info.geometry.width;
+        }
+
+        if let Some(outlines) = self.outlines {
+            svg.push_str("<g");
+            push_fill(svg, style.foreground);
+            svg.push_str(">\n");
+            for (info, &(x, y)) in glyphs.iter().zip(&origins) {
+                if info.glyph == GLYPH_EMPTY || info.glyph & GLYPH_UNKNOWN_FLAG != 0 {
+                    continue;
+                }
+                if let Some(path) = outlines.glyph_path(&font, info.glyph) {
+                    write!(svg, "<path transform=\"translate({} {})\" d=\"{}\"/>\n",
+                           px(x), px(y), xml_text(&path)).unwrap();
+                }
+            }
+            svg.push_str("</g>\n");
+        } else {
+            svg.push_str("<text");
+            push_font(svg, &font);
+            push_fill(svg, style.foreground);
+            svg.push('>');
+            if analysis.flags() & ANALYSIS_FLAG_IS_ELLIPSIS != 0 {
+                // The item covers the hidden text, the glyphs are those of
+                // the ellipsis.
+                if let Some(&(x, y)) = origins.first() {
+                    write!(svg, "<tspan x=\"{}\" y=\"{}\">\u{2026}</tspan>", px(x), px(y)).unwrap();
+                }
+            } else {
+                self.push_clusters(svg, text, run, &origins);
+            }
+            svg.push_str("</text>\n");
+        }
+
+        if style.underline == Underline::None && !style.strikethrough {
+            return;
+        }
+        let metrics = match font.get_metrics(analysis.language().as_ref()) {
+            Some(metrics) => metrics,
+            None => return,
+        };
+        let underline_color = style.underline_color.or(style.foreground);
+        let position = baseline - metrics.get_underline_position();
+        let thickness = metrics.get_underline_thickness();
+        match style.underline {
+            // Pango draws low and error underlines in relation to the ink
+            // extents and as a wave, a plain line is close enough here.
+            Underline::Single | Underline::Low | Underline::Error => {
+                push_rect(svg, logical.x, position, logical.width, thickness, underline_color);
+            }
+            Underline::Double => {
+                push_rect(svg, logical.x, position, logical.width, thickness, underline_color);
+                push_rect(svg, logical.x, position + 2 * thickness, logical.width, thickness, underline_color);
+            }
+            _ => (),
+        }
+        if style.strikethrough {
+            let color = style.strikethrough_color.or(style.foreground);
+            push_rect(svg, logical.x, baseline - metrics.get_strikethrough_position(), logical.width,
+                      metrics.get_strikethrough_thickness(), color);
+        }
+    }
+
+    // One `<tspan>` per cluster, at the origin of its leftmost glyph.
+    fn push_clusters(&self, svg: &mut String, text: &str, run: &LayoutRun, origins: &[(i32, i32)]) {
+        for cluster in run.clusters(text) {
+            // Right-to-left clusters count down from `start_glyph`.
+            let first = if cluster.start_glyph <= cluster.end_glyph {
+                cluster.start_glyph
+            } else {
+                cluster.end_glyph + 1
+            };
+            if cluster.start_glyph == cluster.end_glyph || first as usize >= origins.len() {
+                continue;
+            }
+            let (x, y) = origins[first as usize];
+            let cluster_text = &text[cluster.start_index as usize..cluster.end_index as usize];
+            write!(svg, "<tspan x=\"{}\" y=\"{}\">{}</tspan>", px(x), px(y), xml_text(cluster_text)).unwrap();
+        }
+    }
+}
+
+// The attributes of a run that affect drawing.
+struct RunStyle {
+    foreground: Option<Color>,
+    background: Option<Color>,
+    underline: Underline,
+    underline_color: Option<Color>,
+    strikethrough: bool,
+    strikethrough_color: Option<Color>,
+    rise: i32,
+}
+
+impl RunStyle {
+    fn new(attrs: &[Attribute]) -> RunStyle {
+        let mut style = RunStyle {
+            foreground: None,
+            background: None,
+            underline: Underline::None,
+            underline_color: None,
+            strikethrough: false,
+            strikethrough_color: None,
+            rise: 0,
+        };
+        for attr in attrs {
+            match attr.kind() {
+                Some(AttrKind::Foreground(color)) => style.foreground = Some(color),
+                Some(AttrKind::Background(color)) => style.background = Some(color),
+                Some(AttrKind::Underline(underline)) => style.underline = underline,
+                Some(AttrKind::UnderlineColor(color)) => style.underline_color = Some(color),
+                Some(AttrKind::Strikethrough(strikethrough)) => style.strikethrough = strikethrough,
+                Some(AttrKind::StrikethroughColor(color)) => style.strikethrough_color = Some(color),
+                Some(AttrKind::Rise(rise)) => style.rise = rise,
+                _ => (),
+            }
+        }
+        style
+    }
+}
+
+// Escapes `text` for XML 1.0, which can't contain most C0 control
+// characters even as references, so they are dropped.
+fn xml_text(text: &str) -> String {
+    let text: String = text.chars().filter(|&c| match c {
+        '\t' | '\n' | '\r' => true,
+        '\u{0}'...'\u{1f}' | '\u{fffe}' | '\u{ffff}' => false,
+        _ => true,
+    }).collect();
+    escape_text(&text)
+}
+
+fn px(units: i32) -> f64 {
+    units as f64 / SCALE as f64
+}
+
+fn color_spec(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.red >> 8, color.green >> 8, color.blue >> 8)
+}
+
+fn push_fill(svg: &mut String, color: Option<Color>) {
+    if let Some(color) = color {
+        write!(svg, " fill=\"{}\"", color_spec(color)).unwrap();
+    }
+}
+
+fn push_rect(svg: &mut String, x: i32, y: i32, width: i32, height: i32, color: Option<Color>) {
+    write!(svg, "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"", px(x), px(y), px(width), px(height)).unwrap();
+    push_fill(svg, color);
+    svg.push_str("/>\n");
+}
+
+fn push_font(svg: &mut String, font: &Font) {
+    let desc = match font.describe_with_absolute_size() {
+        Some(desc) => desc,
+        None => return,
+    };
+    if let Some(family) = desc.get_family() {
+        write!(svg, " font-family=\"{}\"", xml_text(&family)).unwrap();
+    }
+    write!(svg, " font-size=\"{}\" font-weight=\"{}\"", px(desc.get_size()), desc.get_weight().to_glib() as i32)
+        .unwrap();
+    match desc.get_style() {
+        Style::Italic => svg.push_str(" font-style=\"italic\""),
+        Style::Oblique => svg.push_str(" font-style=\"oblique\""),
+        _ => (),
+    }
+    if desc.get_variant() == Variant::SmallCaps {
+        svg.push_str(" font-variant=\"small-caps\"");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use widgets::test_util;
+    use {EllipsizeMode, SCALE};
+    use super::*;
+
+    #[test]
+    fn tspan_positions_and_colors() {
+        let layout = test_util::layout("<span foreground=\"#ff0000\">ab</span>c");
+        let svg = SvgExport::new().render(&layout);
+        let runs = layout.get_line(0).unwrap().runs();
+        assert_eq!(runs.len(), 2);
+        let widths: Vec<i32> = runs[0].glyphs().glyphs().iter().map(|info| info.geometry.width).collect();
+        let y = px(layout.get_baseline());
+
+        assert!(svg.contains(&format!(" fill=\"#ff0000\"><tspan x=\"0\" y=\"{}\">a</tspan>\
+                                       <tspan x=\"{}\" y=\"{}\">b</tspan></text>",
+                                      y, px(widths[0]), y)),
+                "{}", svg);
+        assert!(svg.contains(&format!("\"><tspan x=\"{}\" y=\"{}\">c</tspan></text>",
+                                      px(widths[0] + widths[1]), y)),
+                "{}", svg);
+        assert_eq!(svg.matches("fill=").count(), 1);
+    }
+
+    #[test]
+    fn ellipsis() {
+        let layout = test_util::layout("Hello world");
+        layout.set_width(30 * SCALE);
+        layout.set_ellipsize(EllipsizeMode::End);
+        let svg = SvgExport::new().render(&layout);
+        assert!(layout.is_ellipsized());
+        assert!(svg.contains("\u{2026}</tspan>"), "{}", svg);
+        assert!(!svg.contains(">d</tspan>"), "{}", svg);
+    }
+
+    #[test]
+    fn control_characters() {
+        let layout = test_util::layout("");
+        layout.set_text("a\u{1}b", -1);
+        let svg = SvgExport::new().render(&layout);
+        assert!(!svg.contains("&#x1;") && !svg.contains('\u{1}'), "{}", svg);
+        assert!(svg.contains(">a</tspan>") && svg.contains(">b</tspan>"), "{}", svg);
+    }
+}
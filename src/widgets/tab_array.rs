@@ -0,0 +1,41 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use std::ptr;
+
+use ffi;
+use glib_ffi;
+use glib::translate::*;
+
+use TabAlign;
+use TabArray;
+
+impl TabArray {
+    /// Creates a tab array with a left-aligned tab stop at each of
+    /// `positions`, given in pixels or in Pango units.
+    pub fn from_positions(positions: &[i32], positions_in_pixels: bool) -> TabArray {
+        let mut tabs = TabArray::new(positions.len() as i32, positions_in_pixels);
+        for (i, &location) in positions.iter().enumerate() {
+            tabs.set_tab(i as i32, TabAlign::Left, location);
+        }
+        tabs
+    }
+
+    /// Returns the alignment and location of every tab stop.
+    pub fn get_tabs(&mut self) -> Vec<(TabAlign, i32)> {
+        unsafe {
+            let size = self.get_size() as usize;
+            let mut alignments = ptr::null_mut();
+            let mut locations = ptr::null_mut();
+            ffi::pango_tab_array_get_tabs(self.to_glib_none_mut().0, &mut alignments, &mut locations);
+            let mut ret = Vec::with_capacity(size);
+            for i in 0..size {
+                ret.push((from_glib(*alignments.offset(i as isize)), *locations.offset(i as isize)));
+            }
+            glib_ffi::g_free(alignments as glib_ffi::gpointer);
+            glib_ffi::g_free(locations as glib_ffi::gpointer);
+            ret
+        }
+    }
+}
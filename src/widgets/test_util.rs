@@ -0,0 +1,29 @@
+// Copyright 2015, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Helpers shared by the unit tests.
+
+use ffi;
+use glib::translate::*;
+
+use {Context, FontMap, FontMapExt, Layout};
+
+// Pango has no font map of its own, the FreeType one needs nothing but
+// fontconfig.
+#[link(name = "pangoft2-1.0")]
+extern "C" {
+    fn pango_ft2_font_map_new() -> *mut ffi::PangoFontMap;
+}
+
+pub fn context() -> Context {
+    let font_map: FontMap = unsafe { from_glib_full(pango_ft2_font_map_new()) };
+    font_map.create_context().unwrap()
+}
+
+/// Returns a layout with the text and attributes of `markup`.
+pub fn layout(markup: &str) -> Layout {
+    let layout = Layout::new(&context());
+    layout.set_markup(markup, -1);
+    layout
+}